@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Group, GroupMembership};
+use crate::errors::DenftError;
+use crate::events::GroupMemberAdded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddGroupMember<'info> {
+    #[account(
+        mut,
+        has_one = admin @ DenftError::Unauthorized
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = GroupMembership::LEN,
+        seeds = [b"group_member", group.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub group_membership: Account<'info, GroupMembership>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: This is the user who will join the group
+    pub member: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn add_group_member(ctx: Context<AddGroupMember>) -> Result<()> {
+        let group = &mut ctx.accounts.group;
+        let group_membership = &mut ctx.accounts.group_membership;
+        let clock = Clock::get()?;
+
+        group_membership.group = group.key();
+        group_membership.member = ctx.accounts.member.key();
+        group_membership.added_by = ctx.accounts.admin.key();
+        group_membership.added_at = clock.unix_timestamp;
+        group_membership.reserved = [0; 16];
+
+        group.add_member();
+
+        emit_cpi!(GroupMemberAdded {
+            group: group.key(),
+            member: ctx.accounts.member.key(),
+            added_by: ctx.accounts.admin.key(),
+            added_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
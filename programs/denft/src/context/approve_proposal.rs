@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, CoOwnership, Proposal};
+use crate::errors::DenftError;
+use crate::events::ProposalApproved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = !proposal.is_executed @ DenftError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let co_ownership = &ctx.accounts.co_ownership;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        let owner_index = co_ownership
+            .owner_index(&ctx.accounts.authority.key())
+            .ok_or(DenftError::NotCoOwner)?;
+
+        require!(!proposal.has_approved(owner_index), DenftError::ProposalAlreadyApproved);
+
+        proposal.approve(owner_index);
+
+        emit_cpi!(ProposalApproved {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            approver: ctx.accounts.authority.key(),
+            approvals: proposal.approval_count(),
+            threshold: co_ownership.threshold,
+            approved_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessPermission, AccessRequest, GrantTerms, DownloadRateLimit};
+use crate::errors::DenftError;
+use crate::events::{AccessGranted, AccessRequestApproved};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveRequest<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [
+            b"access_request",
+            file_record.key().as_ref(),
+            requester.key().as_ref()
+        ],
+        bump,
+        has_one = requester @ DenftError::InvalidAccessPermission
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AccessPermission::LEN,
+        seeds = [
+            b"access",
+            file_record.key().as_ref(),
+            requester.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Requester recorded in the access request, receives its rent back
+    #[account(mut)]
+    pub requester: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn approve_request(
+        ctx: Context<ApproveRequest>,
+        expires_at: Option<i64>,
+        max_downloads: Option<u32>,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
+    ) -> Result<()> {
+        let access_request = &ctx.accounts.access_request;
+        let access_permission = &mut ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        require!(
+            !access_request.is_expired(clock.unix_timestamp),
+            DenftError::AccessRequestExpired
+        );
+
+        let permissions = access_request.permissions;
+        let terms = GrantTerms {
+            permissions,
+            not_before,
+            expires_at,
+            max_downloads,
+            rate_limit,
+        };
+        terms.validate(clock.unix_timestamp)?;
+
+        access_permission.grant(
+            ctx.accounts.file_record.key(),
+            access_request.requester,
+            &terms,
+            ctx.accounts.authority.key(),
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+
+        emit_cpi!(AccessGranted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            accessor: access_request.requester,
+            permissions,
+            granted_at: clock.unix_timestamp,
+            expires_at,
+            not_before,
+        });
+
+        emit_cpi!(AccessRequestApproved {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            requester: access_request.requester,
+            permissions,
+            approved_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, Attestation};
+use crate::state::constants::MAX_CLAIM_TYPE_LENGTH;
+use crate::errors::DenftError;
+use crate::events::FileAttested;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AttestFile<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = attester,
+        space = Attestation::LEN,
+        seeds = [b"attestation", file_record.key().as_ref(), attester.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn attest_file(
+        ctx: Context<AttestFile>,
+        file_hash: [u8; 32],
+        claim_type: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let attestation = &mut ctx.accounts.attestation;
+        let clock = Clock::get()?;
+
+        // The attester signs off on the exact content hash, not just the account
+        require!(file_record.verify_hash(&file_hash), DenftError::FileHashMismatch);
+        require!(file_record.is_accessible(), DenftError::FileNotActive);
+        require!(claim_type.len() <= MAX_CLAIM_TYPE_LENGTH, DenftError::ClaimTypeTooLong);
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > clock.unix_timestamp, DenftError::InvalidExpirationTime);
+        }
+
+        attestation.file_record = file_record.key();
+        attestation.attester = ctx.accounts.attester.key();
+        attestation.file_hash = file_hash;
+        attestation.claim_type = claim_type;
+        attestation.attested_at = clock.unix_timestamp;
+        attestation.expires_at = expires_at;
+        attestation.revoked = false;
+        attestation.revoked_at = None;
+        attestation.reserved = [0; 16];
+
+        emit_cpi!(FileAttested {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            attestation: attestation.key(),
+            attester: ctx.accounts.attester.key(),
+            file_hash,
+            claim_type: attestation.claim_type.clone(),
+            expires_at,
+            attested_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
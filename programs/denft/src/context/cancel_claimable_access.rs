@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, ClaimableGrant};
+use crate::errors::DenftError;
+use crate::events::ClaimableAccessCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelClaimableAccess<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"claimable",
+            claimable_grant.file_record.as_ref(),
+            claimable_grant.claim_key_hash.as_ref()
+        ],
+        bump,
+        constraint = claimable_grant.created_by == authority.key() @ DenftError::Unauthorized
+    )]
+    pub claimable_grant: Account<'info, ClaimableGrant>,
+
+    /// CHECK: The shared file; may already have been purged, so it is read only if still open
+    #[account(mut, address = claimable_grant.file_record)]
+    pub file_record: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn cancel_claimable_access(ctx: Context<CancelClaimableAccess>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        emit_cpi!(ClaimableAccessCancelled {
+            file_id: ctx.accounts.claimable_grant.file_record,
+            sequence: FileRecord::next_event_sequence_of(&ctx.accounts.file_record)?,
+            claimable_grant: ctx.accounts.claimable_grant.key(),
+            owner: ctx.accounts.authority.key(),
+            cancelled_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
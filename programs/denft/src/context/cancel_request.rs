@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessRequest};
+use crate::errors::DenftError;
+use crate::events::AccessRequestCancelled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelRequest<'info> {
+    #[account(
+        mut,
+        close = requester,
+        seeds = [
+            b"access_request",
+            access_request.file_record.as_ref(),
+            requester.key().as_ref()
+        ],
+        bump,
+        has_one = requester @ DenftError::Unauthorized
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+
+    /// CHECK: The requested file; may already have been purged, so it is read only if still open
+    #[account(mut, address = access_request.file_record)]
+    pub file_record: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn cancel_request(ctx: Context<CancelRequest>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        emit_cpi!(AccessRequestCancelled {
+            file_id: ctx.accounts.access_request.file_record,
+            sequence: FileRecord::next_event_sequence_of(&ctx.accounts.file_record)?,
+            requester: ctx.accounts.requester.key(),
+            cancelled_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Credential, CredentialStatusList, CredentialStatus};
+
+#[derive(Accounts)]
+pub struct CheckCredential<'info> {
+    #[account(
+        seeds = [b"credential", credential.issuer.as_ref(), credential.credential_hash.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(address = credential.status_list)]
+    pub status_list: Box<Account<'info, CredentialStatusList>>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn check_credential(ctx: Context<CheckCredential>) -> Result<CredentialStatus> {
+        let clock = Clock::get()?;
+
+        // Returned to the caller through return data
+        Ok(ctx.accounts.credential.status(&ctx.accounts.status_list, clock.unix_timestamp))
+    }
+}
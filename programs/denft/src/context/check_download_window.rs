@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::AccessPermission;
+
+#[derive(Accounts)]
+pub struct CheckDownloadWindow<'info> {
+    #[account(
+        seeds = [
+            b"access",
+            access_permission.file_record.as_ref(),
+            access_permission.accessor.as_ref()
+        ],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn check_download_window(ctx: Context<CheckDownloadWindow>) -> Result<Option<i64>> {
+        let clock = Clock::get()?;
+
+        // Returned to the caller through return data
+        Ok(ctx.accounts.access_permission.rate_limited_until(clock.unix_timestamp))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessPermission, ClaimableGrant};
+use crate::errors::DenftError;
+use crate::events::{AccessGranted, AccessClaimed};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimAccess<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_accessible() @ DenftError::FileNotActive
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        close = created_by,
+        seeds = [
+            b"claimable",
+            file_record.key().as_ref(),
+            claimable_grant.claim_key_hash.as_ref()
+        ],
+        bump,
+        has_one = created_by @ DenftError::Unauthorized,
+        constraint = claimable_grant.matches_claim_key(&claim_key.key()) @ DenftError::InvalidClaimKey
+    )]
+    pub claimable_grant: Account<'info, ClaimableGrant>,
+
+    #[account(
+        init,
+        payer = claimer,
+        space = 8 + AccessPermission::LEN,
+        seeds = [
+            b"access",
+            file_record.key().as_ref(),
+            claimer.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    /// Wallet the access is bound to
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// One-time key from the share link, proving possession of the secret
+    pub claim_key: Signer<'info>,
+
+    /// CHECK: Owner who created the link, receives its rent back
+    #[account(mut)]
+    pub created_by: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn claim_access(ctx: Context<ClaimAccess>) -> Result<()> {
+        let claimable_grant = &ctx.accounts.claimable_grant;
+        let access_permission = &mut ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        require!(
+            claimable_grant.is_claimable(clock.unix_timestamp),
+            DenftError::ClaimExpired
+        );
+
+        // Links created before a purge or reactivation do not carry over to the new upload
+        require!(
+            claimable_grant.created_at >= ctx.accounts.file_record.timestamp,
+            DenftError::StaleFileReference
+        );
+
+        // The resulting permission may have expired while the link was pending
+        let terms = claimable_grant.terms();
+        terms.validate(clock.unix_timestamp)?;
+
+        let accessor = ctx.accounts.claimer.key();
+
+        access_permission.grant(
+            ctx.accounts.file_record.key(),
+            accessor,
+            &terms,
+            claimable_grant.created_by,
+            accessor,
+            clock.unix_timestamp,
+        );
+
+        emit_cpi!(AccessGranted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: claimable_grant.created_by,
+            accessor,
+            permissions: claimable_grant.permissions,
+            granted_at: clock.unix_timestamp,
+            expires_at: claimable_grant.expires_at,
+            not_before: claimable_grant.not_before,
+        });
+
+        emit_cpi!(AccessClaimed {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            claimable_grant: claimable_grant.key(),
+            owner: claimable_grant.created_by,
+            accessor,
+            permissions: claimable_grant.permissions,
+            claimed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
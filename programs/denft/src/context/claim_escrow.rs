@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::EscrowReleased;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimEscrow<'info> {
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = client @ DenftError::InvalidEscrow,
+        has_one = freelancer @ DenftError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// CHECK: Client recorded in the escrow, receives the rent back
+    #[account(mut)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub freelancer: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn claim_escrow(ctx: Context<ClaimEscrow>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+
+        // A client who neither confirms nor disputes in time accepts the delivery
+        require!(escrow.is_claimable(clock.unix_timestamp), DenftError::EscrowNotClaimable);
+
+        Escrow::pay_out(
+            &escrow.to_account_info(),
+            &ctx.accounts.freelancer.to_account_info(),
+            escrow.amount,
+        )?;
+
+        emit_cpi!(EscrowReleased {
+            escrow: escrow.key(),
+            client: escrow.client,
+            freelancer: escrow.freelancer,
+            file_hash: escrow.file_hash,
+            amount: escrow.amount,
+            released_by: ctx.accounts.freelancer.key(),
+            released_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
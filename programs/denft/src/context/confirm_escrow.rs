@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::EscrowReleased;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfirmEscrow<'info> {
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = client @ DenftError::Unauthorized,
+        has_one = freelancer @ DenftError::InvalidEscrow
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// CHECK: Freelancer recorded in the escrow, receives the funds
+    #[account(mut)]
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn confirm_escrow(ctx: Context<ConfirmEscrow>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+
+        // The client may release early, even before a delivery is recorded, but not over a pending dispute
+        require!(!escrow.is_disputed(), DenftError::EscrowDisputed);
+
+        Escrow::pay_out(
+            &escrow.to_account_info(),
+            &ctx.accounts.freelancer.to_account_info(),
+            escrow.amount,
+        )?;
+
+        emit_cpi!(EscrowReleased {
+            escrow: escrow.key(),
+            client: escrow.client,
+            freelancer: escrow.freelancer,
+            file_hash: escrow.file_hash,
+            amount: escrow.amount,
+            released_by: ctx.accounts.client.key(),
+            released_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, ClaimableGrant, GrantTerms, DownloadRateLimit};
+use crate::errors::DenftError;
+use crate::events::ClaimableAccessCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(claim_key_hash: [u8; 32])]
+pub struct CreateClaimableAccess<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ClaimableGrant::LEN,
+        seeds = [
+            b"claimable",
+            file_record.key().as_ref(),
+            claim_key_hash.as_ref()
+        ],
+        bump
+    )]
+    pub claimable_grant: Account<'info, ClaimableGrant>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_claimable_access(
+        ctx: Context<CreateClaimableAccess>,
+        claim_key_hash: [u8; 32],
+        permissions: u8,
+        expires_at: Option<i64>,
+        max_downloads: Option<u32>,
+        claim_expires_at: i64,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
+    ) -> Result<()> {
+        let claimable_grant = &mut ctx.accounts.claimable_grant;
+        let clock = Clock::get()?;
+
+        GrantTerms { permissions, not_before, expires_at, max_downloads, rate_limit }
+            .validate(clock.unix_timestamp)?;
+        require!(claim_expires_at > clock.unix_timestamp, DenftError::InvalidExpirationTime);
+
+        claimable_grant.file_record = ctx.accounts.file_record.key();
+        claimable_grant.claim_key_hash = claim_key_hash;
+        claimable_grant.permissions = permissions;
+        claimable_grant.expires_at = expires_at;
+        claimable_grant.max_downloads = max_downloads;
+        claimable_grant.claim_expires_at = claim_expires_at;
+        claimable_grant.created_by = ctx.accounts.authority.key();
+        claimable_grant.created_at = clock.unix_timestamp;
+        claimable_grant.not_before = not_before;
+        claimable_grant.rate_limit = rate_limit;
+        claimable_grant.reserved = [0; 7];
+
+        emit_cpi!(ClaimableAccessCreated {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            claimable_grant: claimable_grant.key(),
+            owner: ctx.accounts.authority.key(),
+            permissions,
+            claim_expires_at,
+            created_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
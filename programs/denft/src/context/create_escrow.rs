@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::EscrowCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(freelancer: Pubkey, file_hash: [u8; 32])]
+pub struct CreateEscrow<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = Escrow::LEN,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.as_ref(), file_hash.as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        freelancer: Pubkey,
+        file_hash: [u8; 32],
+        amount: u64,
+        deadline: i64,
+        dispute_window: i64,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let client = ctx.accounts.client.key();
+
+        require!(
+            amount > 0 && deadline > clock.unix_timestamp && dispute_window > 0,
+            DenftError::InvalidEscrowTerms
+        );
+        // Disputes are settled by a third party, never by either side of the deal
+        require!(
+            arbiter != Pubkey::default() && arbiter != client && arbiter != freelancer,
+            DenftError::InvalidEscrowTerms
+        );
+
+        let escrow = &mut ctx.accounts.escrow;
+
+        escrow.client = client;
+        escrow.freelancer = freelancer;
+        escrow.arbiter = arbiter;
+        escrow.file_hash = file_hash;
+        escrow.amount = amount;
+        escrow.created_at = clock.unix_timestamp;
+        escrow.deadline = deadline;
+        escrow.dispute_window = dispute_window;
+        escrow.delivered_at = None;
+        escrow.disputed_at = None;
+        escrow.file_record = Pubkey::default();
+        escrow.reserved = [0; 16];
+
+        // The funds sit in the escrow account next to its rent
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.client.to_account_info(),
+                    to: escrow.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit_cpi!(EscrowCreated {
+            escrow: escrow.key(),
+            client,
+            freelancer,
+            arbiter,
+            file_hash,
+            amount,
+            deadline,
+            dispute_window,
+            created_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::ExistenceProof;
+use crate::events::ExistenceProofCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(file_hash: [u8; 32])]
+pub struct CreateExistenceProof<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = ExistenceProof::LEN,
+        seeds = [b"proof", owner.key().as_ref(), file_hash.as_ref()],
+        bump
+    )]
+    pub existence_proof: Account<'info, ExistenceProof>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn create_existence_proof(ctx: Context<CreateExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+        let existence_proof = &mut ctx.accounts.existence_proof;
+        let clock = Clock::get()?;
+
+        // Pure timestamping: no user account, so nothing is charged against storage quota
+        existence_proof.owner = ctx.accounts.owner.key();
+        existence_proof.file_hash = file_hash;
+        existence_proof.slot = clock.slot;
+        existence_proof.timestamp = clock.unix_timestamp;
+
+        emit_cpi!(ExistenceProofCreated {
+            proof: existence_proof.key(),
+            owner: ctx.accounts.owner.key(),
+            file_hash,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Group;
+use crate::state::constants::MAX_GROUP_NAME_LENGTH;
+use crate::errors::DenftError;
+use crate::events::GroupCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateGroup<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Group::LEN,
+        seeds = [b"group", admin.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn create_group(ctx: Context<CreateGroup>, name: String) -> Result<()> {
+        require!(name.len() <= MAX_GROUP_NAME_LENGTH, DenftError::GroupNameTooLong);
+
+        let group = &mut ctx.accounts.group;
+        let clock = Clock::get()?;
+
+        group.admin = ctx.accounts.admin.key();
+        group.name = name;
+        group.member_count = 0;
+        group.created_at = clock.unix_timestamp;
+        group.reserved = [0; 32];
+
+        emit_cpi!(GroupCreated {
+            group: group.key(),
+            admin: ctx.accounts.admin.key(),
+            name: group.name.clone(),
+            created_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, CoOwnership, Proposal, ProposalAction, GrantTerms};
+use crate::errors::DenftError;
+use crate::events::ProposalCreated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        constraint = file_record.co_owned @ DenftError::Unauthorized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump,
+        constraint = co_ownership.is_owner(&authority.key()) @ DenftError::NotCoOwner
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Proposal::LEN,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            co_ownership.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        let co_ownership = &mut ctx.accounts.co_ownership;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        // Reject grants that could never execute
+        if let ProposalAction::GrantAccess { permissions, expires_at, max_downloads, not_before, rate_limit, .. } = action {
+            GrantTerms { permissions, not_before, expires_at, max_downloads, rate_limit }
+                .validate(clock.unix_timestamp)?;
+        }
+
+        let owner_index = co_ownership
+            .owner_index(&ctx.accounts.authority.key())
+            .ok_or(DenftError::NotCoOwner)?;
+
+        proposal.file_record = ctx.accounts.file_record.key();
+        proposal.proposal_id = co_ownership.proposal_count;
+        proposal.proposer = ctx.accounts.authority.key();
+        proposal.action = action.clone();
+        proposal.approvals = 0;
+        proposal.created_at = clock.unix_timestamp;
+        proposal.is_executed = false;
+        proposal.executed_at = None;
+        proposal.reserved = [0; 16];
+
+        // Proposing counts as the proposer's approval
+        proposal.approve(owner_index);
+
+        co_ownership.proposal_count += 1;
+
+        emit_cpi!(ProposalCreated {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            proposal_id: proposal.proposal_id,
+            proposer: ctx.accounts.authority.key(),
+            action,
+            created_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
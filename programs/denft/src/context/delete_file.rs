@@ -1,25 +1,31 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use crate::{UserAccount, FileRecord, DenftError, FileDeleted};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DeleteFile<'info> {
     #[account(
         mut,
-        seeds = [b"user", authority.key().as_ref()],
+        seeds = [b"user", file_record.uploader.as_ref()],
         bump
     )]
     pub user_account: Account<'info, UserAccount>,
     
     #[account(
         mut,
-        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
     )]
     pub file_record: Account<'info, FileRecord>,
     
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
     pub authority: Signer<'info>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
     use anchor_lang::solana_program::clock::Clock;
 
@@ -29,6 +35,7 @@ pub mod handler {
         let clock = Clock::get()?;
 
         require!(file_record.is_accessible(), DenftError::FileAlreadyDeleted);
+        file_record.check_not_locked(clock.unix_timestamp)?;
 
         let file_size = file_record.file_size;
 
@@ -38,10 +45,13 @@ pub mod handler {
         // Mark file as inactive using helper method
         file_record.mark_deleted(clock.unix_timestamp);
 
-        emit!(FileDeleted {
+        emit_cpi!(FileDeleted {
             file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
             owner: ctx.accounts.authority.key(),
             deleted_at: clock.unix_timestamp,
+            storage_used: ctx.accounts.user_account.storage_used,
+            file_count: ctx.accounts.user_account.file_count,
         });
 
         Ok(())
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Escrow, FileRecord, AccessPermission};
+use crate::errors::DenftError;
+use crate::events::EscrowDelivered;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeliverEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.client.as_ref(), freelancer.key().as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = freelancer @ DenftError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// The deliverable, uploaded by the freelancer with the agreed hash
+    #[account(
+        mut,
+        seeds = [b"file", freelancer.key().as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        constraint = file_record.is_accessible() @ DenftError::FileNotActive
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// The client's grant on the deliverable
+    #[account(
+        seeds = [b"access", file_record.key().as_ref(), escrow.client.as_ref()],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    pub freelancer: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn deliver_escrow(ctx: Context<DeliverEscrow>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let file_record = &mut ctx.accounts.file_record;
+        let access_permission = &ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        require!(!escrow.is_delivered(), DenftError::EscrowAlreadyDelivered);
+        require!(clock.unix_timestamp <= escrow.deadline, DenftError::EscrowDeadlinePassed);
+
+        // The client must be able to download the deliverable before confirming or disputing it
+        access_permission.check_valid(clock.unix_timestamp)?;
+        require!(!access_permission.is_stale(file_record), DenftError::StaleFileReference);
+        require!(
+            access_permission.can_download(),
+            DenftError::MissingDownloadPermission
+        );
+
+        escrow.delivered_at = Some(clock.unix_timestamp);
+        escrow.file_record = file_record.key();
+
+        emit_cpi!(EscrowDelivered {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            escrow: escrow.key(),
+            client: escrow.client,
+            freelancer: escrow.freelancer,
+            delivered_at: clock.unix_timestamp,
+            disputable_until: clock.unix_timestamp + escrow.dispute_window,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessRequest};
+use crate::errors::DenftError;
+use crate::events::AccessRequestDenied;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DenyRequest<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [
+            b"access_request",
+            file_record.key().as_ref(),
+            requester.key().as_ref()
+        ],
+        bump,
+        has_one = requester @ DenftError::InvalidAccessPermission
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Requester recorded in the access request, receives its rent back
+    #[account(mut)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn deny_request(ctx: Context<DenyRequest>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        emit_cpi!(AccessRequestDenied {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            requester: ctx.accounts.requester.key(),
+            denied_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount};
+
+use crate::state::FileRecord;
+use crate::errors::DenftError;
+use crate::events::FileDetokenized;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DetokenizeFile<'info> {
+    #[account(
+        mut,
+        constraint = file_record.ownership_mint == Some(mint.key()) @ DenftError::FileNotTokenized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        constraint = holder_token_account.amount == 1 @ DenftError::Unauthorized
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn detokenize_file(ctx: Context<DetokenizeFile>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        // Return the emptied token account's rent to the holder
+        token::close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ))?;
+
+        // Ownership is pinned to whoever held the token
+        let file_record = &mut ctx.accounts.file_record;
+        file_record.ownership_mint = None;
+        file_record.owner = ctx.accounts.authority.key();
+
+        emit_cpi!(FileDetokenized {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            mint: ctx.accounts.mint.key(),
+            owner: ctx.accounts.authority.key(),
+            detokenized_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::EscrowDisputed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DisputeEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", client.key().as_ref(), escrow.freelancer.as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = client @ DenftError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub client: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn dispute_escrow(ctx: Context<DisputeEscrow>) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        let clock = Clock::get()?;
+
+        // The funds stay locked; only the arbiter can move them from here
+        require!(escrow.is_disputable(clock.unix_timestamp), DenftError::EscrowNotDisputable);
+
+        escrow.disputed_at = Some(clock.unix_timestamp);
+
+        emit_cpi!(EscrowDisputed {
+            escrow: escrow.key(),
+            client: escrow.client,
+            freelancer: escrow.freelancer,
+            arbiter: escrow.arbiter,
+            file_hash: escrow.file_hash,
+            disputed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
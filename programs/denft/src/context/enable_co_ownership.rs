@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, CoOwnership};
+use crate::errors::DenftError;
+use crate::events::CoOwnershipEnabled;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnableCoOwnership<'info> {
+    #[account(
+        mut,
+        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::FileAlreadyCoOwned,
+        constraint = !file_record.is_tokenized() @ DenftError::FileTokenized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CoOwnership::LEN,
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    /// CHECK: The file's listing address; a listing keeps paying the single owner, so it must be removed first
+    #[account(
+        seeds = [b"listing", file_record.key().as_ref()],
+        bump,
+        constraint = access_listing.data_is_empty() @ DenftError::FileListedForSale
+    )]
+    pub access_listing: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn enable_co_ownership(
+        ctx: Context<EnableCoOwnership>,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        // The current owner must remain part of the owner set
+        require!(
+            owners.contains(&ctx.accounts.authority.key()),
+            DenftError::InvalidCoOwners
+        );
+        require!(
+            CoOwnership::is_valid_owner_set(&owners, threshold),
+            DenftError::InvalidCoOwners
+        );
+
+        let file_record = &mut ctx.accounts.file_record;
+        let co_ownership = &mut ctx.accounts.co_ownership;
+        let clock = Clock::get()?;
+
+        co_ownership.file_record = file_record.key();
+        co_ownership.owners = owners;
+        co_ownership.threshold = threshold;
+        co_ownership.proposal_count = 0;
+        co_ownership.created_at = clock.unix_timestamp;
+        co_ownership.reserved = [0; 32];
+
+        // From now on privileged operations must go through proposals
+        file_record.co_owned = true;
+
+        emit_cpi!(CoOwnershipEnabled {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            owners: co_ownership.owners.clone(),
+            threshold,
+            enabled_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
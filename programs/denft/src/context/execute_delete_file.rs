@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{UserAccount, FileRecord, CoOwnership, Proposal, ProposalAction};
+use crate::errors::DenftError;
+use crate::events::{FileDeleted, ProposalExecuted};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteDeleteFile<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", file_record.uploader.as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = proposal.action == ProposalAction::DeleteFile @ DenftError::ProposalActionMismatch
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn execute_delete_file(ctx: Context<ExecuteDeleteFile>) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let user_account = &mut ctx.accounts.user_account;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        proposal.check_executable(&ctx.accounts.co_ownership, &ctx.accounts.authority.key())?;
+        require!(file_record.is_accessible(), DenftError::FileAlreadyDeleted);
+        file_record.check_not_locked(clock.unix_timestamp)?;
+
+        // Storage is released from the original uploader's quota
+        user_account.remove_file(file_record.file_size);
+        file_record.mark_deleted(clock.unix_timestamp);
+        proposal.mark_executed(clock.unix_timestamp);
+
+        emit_cpi!(FileDeleted {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            owner: file_record.owner,
+            deleted_at: clock.unix_timestamp,
+            storage_used: ctx.accounts.user_account.storage_used,
+            file_count: ctx.accounts.user_account.file_count,
+        });
+
+        emit_cpi!(ProposalExecuted {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            executor: ctx.accounts.authority.key(),
+            executed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessPermission, GrantTerms, CoOwnership, Proposal, ProposalAction};
+use crate::errors::DenftError;
+use crate::events::{AccessGranted, ProposalExecuted};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteGrantAccess<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AccessPermission::LEN,
+        seeds = [
+            b"access",
+            file_record.key().as_ref(),
+            accessor.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Must match the accessor recorded in the proposal
+    pub accessor: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn execute_grant_access(ctx: Context<ExecuteGrantAccess>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let access_permission = &mut ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        proposal.check_executable(&ctx.accounts.co_ownership, &ctx.accounts.authority.key())?;
+
+        let ProposalAction::GrantAccess { accessor, permissions, expires_at, max_downloads, not_before, rate_limit } = proposal.action else {
+            return err!(DenftError::ProposalActionMismatch);
+        };
+        require_keys_eq!(accessor, ctx.accounts.accessor.key(), DenftError::ProposalActionMismatch);
+
+        let terms = GrantTerms {
+            permissions,
+            not_before,
+            expires_at,
+            max_downloads,
+            rate_limit,
+        };
+
+        // The expiry may have passed while the proposal was collecting approvals
+        terms.validate(clock.unix_timestamp)?;
+
+        access_permission.grant(
+            ctx.accounts.file_record.key(),
+            accessor,
+            &terms,
+            ctx.accounts.authority.key(),
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+        proposal.mark_executed(clock.unix_timestamp);
+
+        emit_cpi!(AccessGranted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            accessor,
+            permissions,
+            granted_at: clock.unix_timestamp,
+            expires_at,
+            not_before,
+        });
+
+        emit_cpi!(ProposalExecuted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            executor: ctx.accounts.authority.key(),
+            executed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
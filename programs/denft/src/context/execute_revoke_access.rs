@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessPermission, CoOwnership, Proposal, ProposalAction};
+use crate::errors::DenftError;
+use crate::events::{AccessRevoked, ProposalExecuted};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteRevokeAccess<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = proposal.action == ProposalAction::RevokeAccess {
+            accessor: access_permission.accessor
+        } @ DenftError::ProposalActionMismatch
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"access",
+            file_record.key().as_ref(),
+            access_permission.accessor.as_ref()
+        ],
+        bump,
+        constraint = access_permission.file_record == file_record.key() @ DenftError::InvalidAccessPermission
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn execute_revoke_access(ctx: Context<ExecuteRevokeAccess>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let access_permission = &mut ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        proposal.check_executable(&ctx.accounts.co_ownership, &ctx.accounts.authority.key())?;
+        require!(access_permission.is_active, DenftError::AccessAlreadyRevoked);
+
+        access_permission.revoke(clock.unix_timestamp);
+        proposal.mark_executed(clock.unix_timestamp);
+
+        emit_cpi!(AccessRevoked {
+            file_id: access_permission.file_record,
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            accessor: access_permission.accessor,
+            revoked_at: clock.unix_timestamp,
+        });
+
+        emit_cpi!(ProposalExecuted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            executor: ctx.accounts.authority.key(),
+            executed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, CoOwnership, Proposal, ProposalAction};
+use crate::errors::DenftError;
+use crate::events::{FilePublicityUpdated, ProposalExecuted};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteUpdateFilePublicity<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        seeds = [b"co_ownership", file_record.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            file_record.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn execute_update_file_publicity(ctx: Context<ExecuteUpdateFilePublicity>) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        proposal.check_executable(&ctx.accounts.co_ownership, &ctx.accounts.authority.key())?;
+
+        let ProposalAction::UpdateFilePublicity { is_public } = proposal.action else {
+            return err!(DenftError::ProposalActionMismatch);
+        };
+
+        // Locked records can be made public but not taken private again
+        if file_record.is_public_verification && !is_public {
+            file_record.check_not_locked(clock.unix_timestamp)?;
+        }
+
+        file_record.is_public_verification = is_public;
+        proposal.mark_executed(clock.unix_timestamp);
+
+        emit_cpi!(FilePublicityUpdated {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            is_public,
+            updated_at: clock.unix_timestamp,
+        });
+
+        emit_cpi!(ProposalExecuted {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            proposal: proposal.key(),
+            executor: ctx.accounts.authority.key(),
+            executed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
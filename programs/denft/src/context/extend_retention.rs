@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::{FileRecord, DenftError, RetentionExtended};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendRetention<'info> {
+    #[account(
+        mut,
+        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal,
+        // Token transfers cannot be blocked, so tokenized files are detokenized before locking
+        constraint = !file_record.is_tokenized() @ DenftError::FileTokenized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+
+    pub fn extend_retention(ctx: Context<ExtendRetention>, retention_until: i64) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        require!(retention_until > clock.unix_timestamp, DenftError::InvalidExpirationTime);
+
+        // Retention only ever moves forward
+        let previous_retention_until = file_record.retention_until;
+        if let Some(current) = previous_retention_until {
+            require!(retention_until > current, DenftError::RetentionCannotBeShortened);
+        }
+
+        file_record.retention_until = Some(retention_until);
+
+        emit_cpi!(RetentionExtended {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            previous_retention_until,
+            retention_until,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,15 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use anchor_lang::solana_program::clock::Clock;
-use anchor_lang::emit;
 
-use crate::state::{FileRecord, AccessPermission};
+use crate::state::{FileRecord, AccessPermission, GrantTerms, DownloadRateLimit};
 use crate::errors::DenftError;
 use crate::events::AccessGranted;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct GrantAccess<'info> {
     #[account(
-        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
     )]
     pub file_record: Account<'info, FileRecord>,
     
@@ -26,6 +29,9 @@ pub struct GrantAccess<'info> {
     )]
     pub access_permission: Account<'info, AccessPermission>,
     
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -35,7 +41,7 @@ pub struct GrantAccess<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
 
     pub fn grant_access(
@@ -44,42 +50,42 @@ pub mod handler {
         permissions: u8,
         expires_at: Option<i64>,
         max_downloads: Option<u32>,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
     ) -> Result<()> {
-        // Validate permissions
-        require!(
-            AccessPermission::is_valid_permission(permissions),
-            DenftError::InvalidPermissions
-        );
-
-        // Validate expiration time if provided
-        if let Some(expiry) = expires_at {
-            let clock = Clock::get()?;
-            require!(expiry > clock.unix_timestamp, DenftError::InvalidExpirationTime);
-        }
-
         let access_permission = &mut ctx.accounts.access_permission;
         let clock = Clock::get()?;
 
-        // Initialize access permission
-        access_permission.file_record = ctx.accounts.file_record.key();
-        access_permission.accessor = accessor;
-        access_permission.permissions = permissions;
-        access_permission.granted_at = clock.unix_timestamp;
-        access_permission.expires_at = expires_at;
-        access_permission.max_downloads = max_downloads.map(|x| x as u64);
-        access_permission.used_downloads = 0;
-        access_permission.granted_by = ctx.accounts.authority.key();
-        access_permission.is_active = true;
-        access_permission.revoked_at = None;
-        access_permission.reserved = [0; 16];
+        let terms = GrantTerms {
+            permissions,
+            not_before,
+            expires_at,
+            max_downloads,
+            rate_limit,
+        };
+
+        // Validate permissions and access window
+        terms.validate(clock.unix_timestamp)?;
+
+        // Initialize access permission using helper method
+        access_permission.grant(
+            ctx.accounts.file_record.key(),
+            accessor,
+            &terms,
+            ctx.accounts.authority.key(),
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
 
-        emit!(AccessGranted {
+        emit_cpi!(AccessGranted {
             file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
             owner: ctx.accounts.authority.key(),
             accessor,
             permissions,
             granted_at: clock.unix_timestamp,
             expires_at,
+            not_before,
         });
 
         Ok(())
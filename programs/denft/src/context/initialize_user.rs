@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::state::{UserAccount};
 
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializeUser<'info> {
   #[account(mut)]
@@ -21,12 +22,11 @@ pub struct InitializeUser<'info> {
 }
 
 
-pub mod handler {
+pub(crate) mod handler {
   use anchor_lang::prelude::*;
   use super::InitializeUser;
-  use crate::state::constants::{MAX_STORAGE_PER_USER, MAX_FILES_PER_USER};
+  use crate::state::constants::{MAX_STORAGE_PER_USER, MAX_FILES_PER_USER, CURRENT_ACCOUNT_VERSION};
   use crate::events::UserInitialized;
-  use anchor_lang::emit;
 
   pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
     let user_account = &mut ctx.accounts.user_account;
@@ -39,9 +39,10 @@ pub mod handler {
     user_account.file_limit = MAX_FILES_PER_USER;
     user_account.created_at = clock.unix_timestamp;
     user_account.is_active = true;
-    user_account.reserved = [0; 64];
+    user_account.version = CURRENT_ACCOUNT_VERSION;
+    user_account.reserved = [0; 63];
 
-    emit!(UserInitialized {
+    emit_cpi!(UserInitialized {
       user: ctx.accounts.authority.key(),
       timestamp: clock.unix_timestamp,
     });
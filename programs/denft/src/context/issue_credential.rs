@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Credential, CredentialStatusList};
+use crate::state::constants::MAX_SCHEMA_ID_LENGTH;
+use crate::errors::DenftError;
+use crate::events::CredentialIssued;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    subject: Pubkey,
+    credential_hash: [u8; 32],
+    schema_id: String,
+    expires_at: Option<i64>,
+    status_list_id: u32
+)]
+pub struct IssueCredential<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = Credential::LEN,
+        seeds = [b"credential", issuer.key().as_ref(), credential_hash.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    /// Created on the issuer's first credential in this list
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = CredentialStatusList::LEN,
+        seeds = [b"status_list", issuer.key().as_ref(), status_list_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub status_list: Box<Account<'info, CredentialStatusList>>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn issue_credential(
+        ctx: Context<IssueCredential>,
+        subject: Pubkey,
+        credential_hash: [u8; 32],
+        schema_id: String,
+        expires_at: Option<i64>,
+        status_list_id: u32,
+    ) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let status_list = &mut ctx.accounts.status_list;
+        let clock = Clock::get()?;
+
+        require!(schema_id.len() <= MAX_SCHEMA_ID_LENGTH, DenftError::SchemaIdTooLong);
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > clock.unix_timestamp, DenftError::InvalidExpirationTime);
+        }
+
+        // Issuers move on to a new list id once a list is full
+        require!(!status_list.is_full(), DenftError::StatusListFull);
+        if status_list.issued == 0 {
+            status_list.issuer = ctx.accounts.issuer.key();
+            status_list.list_id = status_list_id;
+        }
+
+        credential.issuer = ctx.accounts.issuer.key();
+        credential.subject = subject;
+        credential.credential_hash = credential_hash;
+        credential.schema_id = schema_id;
+        credential.issued_at = clock.unix_timestamp;
+        credential.expires_at = expires_at;
+        credential.revoked = false;
+        credential.revoked_at = None;
+        credential.status_list = status_list.key();
+        credential.status_index = status_list.allocate();
+        credential.reserved = [0; 16];
+
+        emit_cpi!(CredentialIssued {
+            credential: credential.key(),
+            issuer: ctx.accounts.issuer.key(),
+            subject,
+            credential_hash,
+            schema_id: credential.schema_id.clone(),
+            expires_at,
+            status_list: credential.status_list,
+            status_index: credential.status_index,
+            issued_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{AccessPermission, migrate_account};
+use crate::state::constants::CURRENT_ACCOUNT_VERSION;
+use crate::events::AccountMigrated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccessPermission<'info> {
+    /// CHECK: Decoded by `migrate_account`, since older layouts may be shorter than `AccessPermission`
+    #[account(mut, owner = crate::ID)]
+    pub access_permission: UncheckedAccount<'info>,
+
+    /// Anyone may migrate; the payer covers any extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn migrate_access_permission(ctx: Context<MigrateAccessPermission>) -> Result<()> {
+        let access_permission = ctx.accounts.access_permission.to_account_info();
+        let clock = Clock::get()?;
+
+        let from_version = migrate_account::<AccessPermission>(
+            &access_permission,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        emit_cpi!(AccountMigrated {
+            account: access_permission.key(),
+            from_version,
+            to_version: CURRENT_ACCOUNT_VERSION,
+            account_size: access_permission.data_len() as u64,
+            migrated_by: ctx.accounts.payer.key(),
+            migrated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
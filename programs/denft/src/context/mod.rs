@@ -4,15 +4,127 @@ pub mod upload_file;
 pub mod grant_access;
 pub mod revoke_access;
 pub mod record_file_access;
+pub mod check_download_window;
 pub mod update_file_publicity;
 pub mod verify_file;
 pub mod delete_file;
+pub mod enable_co_ownership;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_delete_file;
+pub mod execute_grant_access;
+pub mod execute_revoke_access;
+pub mod execute_update_file_publicity;
+pub mod request_access;
+pub mod approve_request;
+pub mod deny_request;
+pub mod cancel_request;
+pub mod sweep_expired_request;
+pub mod create_group;
+pub mod add_group_member;
+pub mod remove_group_member;
+pub mod create_claimable_access;
+pub mod claim_access;
+pub mod cancel_claimable_access;
+pub mod update_file_release;
+pub mod sweep_expired_grant;
+pub mod initialize_access_log;
+pub mod resize_access_log;
+pub mod restore_file;
+pub mod purge_file;
+pub mod extend_retention;
+pub mod set_legal_hold;
+pub mod set_hold_authority;
+pub mod create_existence_proof;
+pub mod verify_existence_proof;
+pub mod attest_file;
+pub mod revoke_attestation;
+pub mod record_signature;
+pub mod issue_credential;
+pub mod revoke_credential;
+pub mod check_credential;
+pub mod tokenize_file;
+pub mod detokenize_file;
+pub mod set_token_gate;
+pub mod remove_token_gate;
+pub mod update_file_metadata;
+pub mod migrate_user_account;
+pub mod migrate_file_record;
+pub mod migrate_access_permission;
+pub mod reactivate_file;
+pub mod set_protocol_fee;
+pub mod set_access_price;
+pub mod remove_access_price;
+pub mod purchase_access;
+pub mod create_escrow;
+pub mod deliver_escrow;
+pub mod confirm_escrow;
+pub mod claim_escrow;
+pub mod refund_escrow;
+pub mod dispute_escrow;
+pub mod resolve_escrow;
 
 pub use initialize_user::*;
 pub use upload_file::*;
 pub use grant_access::*;
 pub use revoke_access::*;
 pub use record_file_access::*;
+pub use check_download_window::*;
 pub use update_file_publicity::*;
 pub use verify_file::*;
-pub use delete_file::*;
\ No newline at end of file
+pub use delete_file::*;
+pub use enable_co_ownership::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_delete_file::*;
+pub use execute_grant_access::*;
+pub use execute_revoke_access::*;
+pub use execute_update_file_publicity::*;
+pub use request_access::*;
+pub use approve_request::*;
+pub use deny_request::*;
+pub use cancel_request::*;
+pub use sweep_expired_request::*;
+pub use create_group::*;
+pub use add_group_member::*;
+pub use remove_group_member::*;
+pub use create_claimable_access::*;
+pub use claim_access::*;
+pub use cancel_claimable_access::*;
+pub use update_file_release::*;
+pub use sweep_expired_grant::*;
+pub use initialize_access_log::*;
+pub use resize_access_log::*;
+pub use restore_file::*;
+pub use purge_file::*;
+pub use extend_retention::*;
+pub use set_legal_hold::*;
+pub use set_hold_authority::*;
+pub use create_existence_proof::*;
+pub use verify_existence_proof::*;
+pub use attest_file::*;
+pub use revoke_attestation::*;
+pub use record_signature::*;
+pub use issue_credential::*;
+pub use revoke_credential::*;
+pub use check_credential::*;
+pub use tokenize_file::*;
+pub use detokenize_file::*;
+pub use set_token_gate::*;
+pub use remove_token_gate::*;
+pub use update_file_metadata::*;
+pub use migrate_user_account::*;
+pub use migrate_file_record::*;
+pub use migrate_access_permission::*;
+pub use reactivate_file::*;
+pub use set_protocol_fee::*;
+pub use set_access_price::*;
+pub use remove_access_price::*;
+pub use purchase_access::*;
+pub use create_escrow::*;
+pub use deliver_escrow::*;
+pub use confirm_escrow::*;
+pub use claim_escrow::*;
+pub use refund_escrow::*;
+pub use dispute_escrow::*;
+pub use resolve_escrow::*;
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessListing, AccessPermission, ProtocolConfig};
+use crate::errors::DenftError;
+use crate::events::{AccessGranted, AccessPurchased};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PurchaseAccess<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_accessible() @ DenftError::FileNotActive,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_listing: Account<'info, AccessListing>,
+
+    /// Reused when the buyer already had a grant that can no longer be used
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + AccessPermission::LEN,
+        seeds = [
+            b"access",
+            file_record.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    /// CHECK: Current owner of the file, receives the price minus the protocol fee
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&seller.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized
+    )]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Protocol fee settings; no fee is charged until they have been initialized
+    #[account(
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: UncheckedAccount<'info>,
+
+    /// CHECK: Fee recipient, required and matched against the protocol config when a fee is due
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn purchase_access(ctx: Context<PurchaseAccess>, max_price: u64) -> Result<()> {
+        let access_listing = &ctx.accounts.access_listing;
+        let clock = Clock::get()?;
+
+        // Protects the buyer from a price raised between signing and execution
+        require!(access_listing.price <= max_price, DenftError::PriceExceedsMaximum);
+
+        // Only the owner can restore a grant they revoked, unless it predates the current file
+        require!(
+            !ctx.accounts.access_permission.is_revoked() ||
+                ctx.accounts.access_permission.is_stale(&ctx.accounts.file_record),
+            DenftError::AccessRevoked
+        );
+
+        // Buyers cannot pay to overwrite a grant they can still use
+        require!(
+            ctx.accounts.access_permission.is_spent(clock.unix_timestamp) ||
+                ctx.accounts.access_permission.is_stale(&ctx.accounts.file_record),
+            DenftError::AccessStillValid
+        );
+
+        let price = access_listing.price;
+        let protocol_fee = match ProtocolConfig::load(&ctx.accounts.protocol_config)? {
+            Some(config) => {
+                let fee = config.fee_for(price);
+                if fee > 0 {
+                    let treasury = ctx.accounts.treasury.as_ref().ok_or(DenftError::InvalidTreasury)?;
+                    require_keys_eq!(treasury.key(), config.treasury, DenftError::InvalidTreasury);
+
+                    system_program::transfer(
+                        CpiContext::new(
+                            ctx.accounts.system_program.to_account_info(),
+                            Transfer {
+                                from: ctx.accounts.buyer.to_account_info(),
+                                to: treasury.to_account_info(),
+                            },
+                        ),
+                        fee,
+                    )?;
+                }
+                fee
+            }
+            None => 0,
+        };
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            price - protocol_fee,
+        )?;
+
+        let terms = access_listing.terms(clock.unix_timestamp);
+        let buyer = ctx.accounts.buyer.key();
+        let access_permission = &mut ctx.accounts.access_permission;
+
+        // A reused account keeps its original rent payer
+        let payer = if access_permission.payer == Pubkey::default() {
+            buyer
+        } else {
+            access_permission.payer
+        };
+
+        access_permission.grant(
+            ctx.accounts.file_record.key(),
+            buyer,
+            &terms,
+            ctx.accounts.seller.key(),
+            payer,
+            clock.unix_timestamp,
+        );
+
+        ctx.accounts.access_listing.sales += 1;
+
+        emit_cpi!(AccessGranted {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.seller.key(),
+            accessor: buyer,
+            permissions: terms.permissions,
+            granted_at: clock.unix_timestamp,
+            expires_at: terms.expires_at,
+            not_before: None,
+        });
+
+        emit_cpi!(AccessPurchased {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            access_listing: ctx.accounts.access_listing.key(),
+            buyer,
+            seller: ctx.accounts.seller.key(),
+            price,
+            protocol_fee,
+            purchased_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use crate::{FileRecord, FileTombstone, AccessLog, DenftError, FilePurged};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PurgeFile<'info> {
+    /// Co-ownership cannot be dissolved, so a co-owned record is never purged; its proposals
+    /// would otherwise collide with those of a later upload at the same address
+    #[account(
+        mut,
+        close = owner,
+        constraint = file_record.uploader == owner.key() @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::FileHasDependents,
+        constraint = !file_record.has_token_gate @ DenftError::FileHasDependents
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Closed with the record; required while the file keeps an access log
+    #[account(
+        mut,
+        seeds = [b"access_log", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_log: Option<Account<'info, AccessLog>>,
+
+    /// CHECK: The file's listing address, which must already have been removed
+    #[account(
+        seeds = [b"listing", file_record.key().as_ref()],
+        bump,
+        constraint = access_listing.data_is_empty() @ DenftError::FileHasDependents
+    )]
+    pub access_listing: UncheckedAccount<'info>,
+
+    /// CHECK: Created on the first purge at this address, funded from the purged record's rent
+    #[account(
+        mut,
+        seeds = [b"tombstone", file_record.key().as_ref()],
+        bump
+    )]
+    pub file_tombstone: UncheckedAccount<'info>,
+
+    /// CHECK: Original uploader, receives the rent of the purged record
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+    use anchor_lang::solana_program::program::invoke_signed;
+    use anchor_lang::solana_program::system_instruction;
+
+    pub fn purge_file(ctx: Context<PurgeFile>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // Holds and retention outlive the trash, so a locked record is never destroyed
+        ctx.accounts.file_record.check_not_locked(clock.unix_timestamp)?;
+
+        // Anyone may purge, but only once the trash retention period has ended
+        require!(
+            ctx.accounts.file_record.is_purgeable(clock.unix_timestamp),
+            DenftError::FileNotPurgeable
+        );
+
+        // The log has no close path of its own, so it goes with the record it belongs to
+        if ctx.accounts.file_record.has_access_log {
+            let access_log = ctx.accounts.access_log.as_ref().ok_or(DenftError::FileHasDependents)?;
+            access_log.close(ctx.accounts.owner.to_account_info())?;
+        }
+
+        let file_id = ctx.accounts.file_record.key();
+        let sequence = ctx.accounts.file_record.next_event_sequence();
+
+        let tombstone_info = ctx.accounts.file_tombstone.to_account_info();
+        if tombstone_info.owner != &crate::ID {
+            // Nobody signs for a permissionless purge, so the record's own rent pays for the tombstone
+            let space = FileTombstone::LEN;
+            let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(tombstone_info.lamports());
+            let file_record_info = ctx.accounts.file_record.to_account_info();
+            **file_record_info.try_borrow_mut_lamports()? -= shortfall;
+            **tombstone_info.try_borrow_mut_lamports()? += shortfall;
+
+            let signer_seeds: &[&[u8]] = &[b"tombstone", file_id.as_ref(), &[ctx.bumps.file_tombstone]];
+            let system_program = ctx.accounts.system_program.to_account_info();
+            invoke_signed(
+                &system_instruction::allocate(&tombstone_info.key(), space as u64),
+                &[tombstone_info.clone(), system_program.clone()],
+                &[signer_seeds],
+            )?;
+            invoke_signed(
+                &system_instruction::assign(&tombstone_info.key(), &crate::ID),
+                &[tombstone_info.clone(), system_program],
+                &[signer_seeds],
+            )?;
+        }
+
+        let tombstone = FileTombstone {
+            file_record: file_id,
+            last_sequence: sequence,
+            purged_at: clock.unix_timestamp,
+            reserved: [0; 16],
+        };
+        tombstone.try_serialize(&mut &mut tombstone_info.try_borrow_mut_data()?[..])?;
+
+        emit_cpi!(FilePurged {
+            file_id,
+            sequence,
+            owner: ctx.accounts.owner.key(),
+            purged_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
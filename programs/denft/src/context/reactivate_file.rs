@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{UserAccount, FileRecord};
+use crate::state::constants::{
+    MAX_IPFS_HASH_LENGTH, MAX_ENCRYPTED_METADATA_LENGTH, MAX_CONTENT_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH,
+};
+use crate::errors::DenftError;
+use crate::events::FileReactivated;
+use crate::generate_verification_id::generate_verification_id;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(ipfs_hash: String, encrypted_metadata: String, content_type: String, description: String)]
+pub struct ReactivateFile<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", file_record.uploader.as_ref()],
+        bump,
+        constraint = user_account.is_active @ DenftError::UserAccountInactive
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Resized to the new strings, like a fresh upload
+    #[account(
+        mut,
+        realloc = FileRecord::space_for(
+            ipfs_hash.len(),
+            encrypted_metadata.len(),
+            content_type.len(),
+            description.len()
+        ),
+        realloc::payer = authority,
+        realloc::zero = false,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn reactivate_file(
+        ctx: Context<ReactivateFile>,
+        ipfs_hash: String,
+        encrypted_metadata: String,
+        content_type: String,
+        description: String,
+    ) -> Result<()> {
+        require!(ipfs_hash.len() <= MAX_IPFS_HASH_LENGTH, DenftError::IpfsHashTooLong);
+        require!(
+            encrypted_metadata.len() <= MAX_ENCRYPTED_METADATA_LENGTH,
+            DenftError::EncryptedMetadataTooLong
+        );
+        require!(content_type.len() <= MAX_CONTENT_TYPE_LENGTH, DenftError::ContentTypeTooLong);
+        require!(description.len() <= MAX_DESCRIPTION_LENGTH, DenftError::DescriptionTooLong);
+
+        let user_account = &mut ctx.accounts.user_account;
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        // Works both in the trash and after it, as long as the record has not been purged
+        require!(!file_record.is_accessible(), DenftError::FileStillActive);
+        require!(user_account.can_add_file(file_record.file_size), DenftError::FileLimitExceeded);
+
+        let previous_verification_id = file_record.verification_id;
+        let previous_access_count = file_record.access_count;
+        let previous_download_count = file_record.download_count;
+        let verification_id = generate_verification_id(&file_record.file_hash, &clock.unix_timestamp);
+
+        // Same content hash, so the size is unchanged; everything else starts over like a new upload.
+        // Moving the upload timestamp also makes earlier grants and share links stale
+        file_record.reactivate(
+            ipfs_hash,
+            encrypted_metadata,
+            content_type,
+            description,
+            clock.unix_timestamp,
+            verification_id,
+        );
+
+        user_account.add_file(file_record.file_size);
+
+        emit_cpi!(FileReactivated {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            previous_verification_id,
+            verification_id: file_record.verification_id,
+            previous_deleted_at: file_record.deleted_at,
+            previous_access_count,
+            previous_download_count,
+            storage_used: user_account.storage_used,
+            file_count: user_account.file_count,
+            reactivated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
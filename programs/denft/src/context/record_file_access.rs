@@ -1,76 +1,158 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use crate::{
-    FileRecord, AccessPermission, DenftError
+    FileRecord, AccessPermission, GroupMembership, AccessLog, TokenGate, DenftError
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RecordFileAccess<'info> {
     #[account(mut)]
     pub file_record: Account<'info, FileRecord>,
     
+    /// Required for everyone except the file owner
     #[account(
         mut,
-        constraint = access_permission.accessor == authority.key() @ DenftError::Unauthorized,
         constraint = access_permission.file_record == file_record.key() @ DenftError::InvalidAccessPermission
     )]
-    pub access_permission: Account<'info, AccessPermission>,
+    pub access_permission: Option<Account<'info, AccessPermission>>,
+
+    /// Proof of membership when the permission was granted to a group
+    #[account(
+        seeds = [b"group_member", group_membership.group.as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub group_membership: Option<Account<'info, GroupMembership>>,
+
+    /// Required when the file keeps an on-chain access log
+    #[account(
+        mut,
+        seeds = [b"access_log", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_log: Option<Account<'info, AccessLog>>,
+
+    /// Holder's token account, lets the holder of a tokenized file skip the permission checks
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Admits token holders without an AccessPermission
+    #[account(
+        seeds = [b"token_gate", file_record.key().as_ref()],
+        bump
+    )]
+    pub token_gate: Option<Account<'info, TokenGate>>,
+
+    /// Caller's token account for the gate's mint or collection NFT
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata of the gating NFT, validated by the token gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
     
     pub authority: Signer<'info>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
     use anchor_lang::solana_program::clock::Clock;
-    use crate::{
-    access_permission::PERMISSION_DOWNLOAD, AccessType, DenftError, FileAccessed, PERMISSION_READ, PERMISSION_SHARE
-};
+    use crate::{AccessType, AccessLogEntry, DenftError, FileAccessed};
 
     pub fn record_file_access(
         ctx: Context<RecordFileAccess>,
         access_type: AccessType,
     ) -> Result<()> {
         let file_record = &mut ctx.accounts.file_record;
-        let access_permission = &mut ctx.accounts.access_permission;
+        let authority = ctx.accounts.authority.key();
         let clock = Clock::get()?;
 
-        // Validate access permission is still valid using helper method
-        require!(
-            access_permission.is_valid(),
-            DenftError::AccessRevoked
-        );
+        // Owners record their own accesses without a self-grant
+        if !file_record.is_owned_by(&authority, ctx.accounts.owner_token_account.as_deref()) {
+            // Grants and gates do not reach into the trash
+            require!(file_record.is_accessible(), DenftError::FileNotActive);
+
+            // Time-locked files are not accessible before their release
+            require!(file_record.is_released(clock.unix_timestamp), DenftError::AccessNotYetValid);
+
+            match ctx.accounts.access_permission.as_mut() {
+                Some(access_permission) => {
+                    // The permission must belong to the signer, directly or through a group they are a member of
+                    match &ctx.accounts.group_membership {
+                        Some(membership) => require!(
+                            membership.group == access_permission.accessor,
+                            DenftError::InvalidGroupMembership
+                        ),
+                        None => require!(access_permission.accessor == authority, DenftError::Unauthorized),
+                    }
+
+                    // Validate access permission is revoked, not yet started or expired using helper method
+                    access_permission.check_valid(clock.unix_timestamp)?;
+                    require!(!access_permission.is_stale(file_record), DenftError::StaleFileReference);
+
+                    // Every access type requires its own permission bit
+                    require!(
+                        access_permission.has_permission(access_type.permission_flag()),
+                        access_type.missing_permission_error()
+                    );
+
+                    // Check download limits for download access
+                    if access_type == AccessType::Download {
+                        require!(
+                            access_permission.can_download(),
+                            DenftError::DownloadLimitExceeded
+                        );
+
+                        // Consume download using helper method
+                        access_permission.consume_download(clock.unix_timestamp)?;
+                    }
+                }
+                None => {
+                    // Token holders are admitted by the gate instead of a per-member grant
+                    let token_gate = ctx
+                        .accounts
+                        .token_gate
+                        .as_ref()
+                        .ok_or(DenftError::Unauthorized)?;
+
+                    token_gate.check_holder(
+                        &authority,
+                        ctx.accounts.gate_token_account.as_deref(),
+                        ctx.accounts.gate_metadata.as_deref(),
+                    )?;
+
+                    require!(
+                        token_gate.has_permission(access_type.permission_flag()),
+                        access_type.missing_permission_error()
+                    );
+                }
+            }
+        }
 
-        // Check download limits for download access
         if access_type == AccessType::Download {
-            require!(
-                access_permission.has_permission(PERMISSION_DOWNLOAD),
-                DenftError::Unauthorized
-            );
-            
-            require!(
-                access_permission.can_download(),
-                DenftError::DownloadLimitExceeded
-            );
-
-            // Consume download using helper method
-            access_permission.consume_download()?;
             file_record.increment_download();
         }
 
         // Update access count using helper method
         file_record.increment_access();
 
-        
+        if file_record.has_access_log {
+            let access_log = ctx
+                .accounts
+                .access_log
+                .as_mut()
+                .ok_or(DenftError::AccessLogRequired)?;
 
-        let access_flag = match access_type {
-            AccessType::Read => PERMISSION_READ,
-            AccessType::Download => PERMISSION_DOWNLOAD,
-            AccessType::Share => PERMISSION_SHARE,
-        };
+            access_log.record(AccessLogEntry {
+                accessor: authority,
+                access_type: access_type.into(),
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+            });
+        }
 
-        emit!(FileAccessed {
+        emit_cpi!(FileAccessed {
             file_id: ctx.accounts.file_record.key(),
-            accessor: ctx.accounts.authority.key(),
-            access_type: access_flag,
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            accessor: authority,
+            access_type,
             timestamp: clock.unix_timestamp,
         });
 
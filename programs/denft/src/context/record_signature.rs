@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::state::{FileRecord, DocumentSignature};
+use crate::errors::DenftError;
+use crate::events::DocumentSigned;
+use crate::verify_ed25519_instruction::verify_ed25519_instruction;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(signer: Pubkey)]
+pub struct RecordSignature<'info> {
+    #[account(mut)]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = DocumentSignature::LEN,
+        seeds = [b"signature", file_record.key().as_ref(), signer.as_ref()],
+        bump
+    )]
+    pub document_signature: Account<'info, DocumentSignature>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: address is constrained to the instructions sysvar
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn record_signature(ctx: Context<RecordSignature>, signer: Pubkey) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        require!(file_record.is_accessible(), DenftError::FileNotActive);
+
+        // The ed25519 check must run as the instruction immediately before this one
+        let instructions = ctx.accounts.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)?;
+        require!(current_index > 0, DenftError::InvalidSignatureInstruction);
+        let ed25519_instruction = load_instruction_at_checked(current_index as usize - 1, &instructions)?;
+
+        let signature = verify_ed25519_instruction(&ed25519_instruction, &signer, &file_record.file_hash)?;
+
+        let document_signature = &mut ctx.accounts.document_signature;
+        document_signature.file_record = file_record.key();
+        document_signature.signer = signer;
+        document_signature.file_hash = file_record.file_hash;
+        document_signature.signature = signature;
+        document_signature.recorded_by = ctx.accounts.payer.key();
+        document_signature.recorded_at = clock.unix_timestamp;
+        document_signature.slot = clock.slot;
+        document_signature.reserved = [0; 16];
+
+        emit_cpi!(DocumentSigned {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            signer,
+            file_hash: file_record.file_hash,
+            signature,
+            recorded_by: ctx.accounts.payer.key(),
+            recorded_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::EscrowRefunded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundEscrow<'info> {
+    /// Closing returns both the escrowed amount and the rent to the client
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"escrow", client.key().as_ref(), escrow.freelancer.as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = client @ DenftError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub client: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn refund_escrow(ctx: Context<RefundEscrow>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+
+        require!(escrow.is_refundable(clock.unix_timestamp), DenftError::EscrowNotRefundable);
+
+        emit_cpi!(EscrowRefunded {
+            escrow: escrow.key(),
+            client: escrow.client,
+            freelancer: escrow.freelancer,
+            file_hash: escrow.file_hash,
+            amount: escrow.amount,
+            refunded_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
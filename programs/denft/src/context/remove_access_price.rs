@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessListing};
+use crate::errors::DenftError;
+use crate::events::AccessPriceRemoved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveAccessPrice<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"listing", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_listing: Account<'info, AccessListing>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn remove_access_price(ctx: Context<RemoveAccessPrice>) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        // Permissions already sold stay valid until they expire or are revoked
+        emit_cpi!(AccessPriceRemoved {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            access_listing: ctx.accounts.access_listing.key(),
+            owner: ctx.accounts.authority.key(),
+            sales: ctx.accounts.access_listing.sales,
+            removed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Group, GroupMembership};
+use crate::errors::DenftError;
+use crate::events::GroupMemberRemoved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveGroupMember<'info> {
+    #[account(
+        mut,
+        has_one = admin @ DenftError::Unauthorized
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"group_member", group.key().as_ref(), group_membership.member.as_ref()],
+        bump
+    )]
+    pub group_membership: Account<'info, GroupMembership>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn remove_group_member(ctx: Context<RemoveGroupMember>) -> Result<()> {
+        let group = &mut ctx.accounts.group;
+        let clock = Clock::get()?;
+
+        group.remove_member();
+
+        emit_cpi!(GroupMemberRemoved {
+            group: group.key(),
+            member: ctx.accounts.group_membership.member,
+            removed_by: ctx.accounts.admin.key(),
+            removed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, TokenGate};
+use crate::errors::DenftError;
+use crate::events::TokenGateRemoved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveTokenGate<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        close = created_by,
+        seeds = [b"token_gate", file_record.key().as_ref()],
+        bump,
+        has_one = created_by @ DenftError::InvalidTokenGate
+    )]
+    pub token_gate: Account<'info, TokenGate>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Owner who attached the gate and paid its rent, refunded even after a transfer
+    #[account(mut)]
+    pub created_by: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn remove_token_gate(ctx: Context<RemoveTokenGate>) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        file_record.has_token_gate = false;
+
+        emit_cpi!(TokenGateRemoved {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            token_gate: ctx.accounts.token_gate.key(),
+            owner: ctx.accounts.authority.key(),
+            removed_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessPermission, AccessRequest};
+use crate::errors::DenftError;
+use crate::events::AccessRequested;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestAccess<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_accessible() @ DenftError::FileNotActive,
+        // Approvals need a single owner, so co-owned files share through proposals instead
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = AccessRequest::LEN,
+        seeds = [
+            b"access_request",
+            file_record.key().as_ref(),
+            requester.key().as_ref()
+        ],
+        bump
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn request_access(
+        ctx: Context<RequestAccess>,
+        permissions: u8,
+        message_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        let access_request = &mut ctx.accounts.access_request;
+        let clock = Clock::get()?;
+
+        require!(
+            AccessPermission::is_valid_permission(permissions),
+            DenftError::InvalidPermissions
+        );
+        require!(expires_at > clock.unix_timestamp, DenftError::InvalidExpirationTime);
+
+        access_request.file_record = ctx.accounts.file_record.key();
+        access_request.requester = ctx.accounts.requester.key();
+        access_request.permissions = permissions;
+        access_request.message_hash = message_hash;
+        access_request.requested_at = clock.unix_timestamp;
+        access_request.expires_at = expires_at;
+        access_request.reserved = [0; 16];
+
+        emit_cpi!(AccessRequested {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.file_record.owner,
+            ownership_mint: ctx.accounts.file_record.ownership_mint,
+            requester: ctx.accounts.requester.key(),
+            permissions,
+            message_hash,
+            requested_at: clock.unix_timestamp,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
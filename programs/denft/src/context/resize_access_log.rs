@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessLog};
+use crate::state::constants::MAX_ACCESS_LOG_CAPACITY;
+use crate::errors::DenftError;
+use crate::events::AccessLogResized;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(capacity: u16)]
+pub struct ResizeAccessLog<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"access_log", file_record.key().as_ref()],
+        bump,
+        realloc = AccessLog::space_required(capacity),
+        realloc::payer = authority,
+        realloc::zero = false
+    )]
+    pub access_log: Account<'info, AccessLog>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn resize_access_log(ctx: Context<ResizeAccessLog>, capacity: u16) -> Result<()> {
+        require!(
+            capacity > 0 && capacity <= MAX_ACCESS_LOG_CAPACITY,
+            DenftError::InvalidAccessLogCapacity
+        );
+
+        let access_log = &mut ctx.accounts.access_log;
+        let clock = Clock::get()?;
+
+        // Shrinking drops the oldest entries so the log fits the new size
+        access_log.resize(capacity);
+
+        emit_cpi!(AccessLogResized {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            access_log: access_log.key(),
+            owner: ctx.accounts.authority.key(),
+            capacity,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::Escrow;
+use crate::errors::DenftError;
+use crate::events::{EscrowReleased, EscrowRefunded};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveEscrow<'info> {
+    /// Closing returns the rent, and the escrowed amount unless released, to the client
+    #[account(
+        mut,
+        close = client,
+        seeds = [b"escrow", client.key().as_ref(), freelancer.key().as_ref(), escrow.file_hash.as_ref()],
+        bump,
+        has_one = client @ DenftError::InvalidEscrow,
+        has_one = freelancer @ DenftError::InvalidEscrow,
+        has_one = arbiter @ DenftError::Unauthorized
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// CHECK: Client recorded in the escrow, receives the rent back
+    #[account(mut)]
+    pub client: UncheckedAccount<'info>,
+
+    /// CHECK: Freelancer recorded in the escrow, receives the funds on release
+    #[account(mut)]
+    pub freelancer: UncheckedAccount<'info>,
+
+    pub arbiter: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn resolve_escrow(ctx: Context<ResolveEscrow>, release_to_freelancer: bool) -> Result<()> {
+        let escrow = &ctx.accounts.escrow;
+        let clock = Clock::get()?;
+
+        require!(escrow.is_disputed(), DenftError::EscrowNotDisputed);
+
+        if release_to_freelancer {
+            Escrow::pay_out(
+                &escrow.to_account_info(),
+                &ctx.accounts.freelancer.to_account_info(),
+                escrow.amount,
+            )?;
+
+            emit_cpi!(EscrowReleased {
+                escrow: escrow.key(),
+                client: escrow.client,
+                freelancer: escrow.freelancer,
+                file_hash: escrow.file_hash,
+                amount: escrow.amount,
+                released_by: ctx.accounts.arbiter.key(),
+                released_at: clock.unix_timestamp,
+            });
+        } else {
+            emit_cpi!(EscrowRefunded {
+                escrow: escrow.key(),
+                client: escrow.client,
+                freelancer: escrow.freelancer,
+                file_hash: escrow.file_hash,
+                amount: escrow.amount,
+                refunded_at: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+}
\ No newline at end of file
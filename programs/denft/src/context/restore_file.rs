@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{UserAccount, FileRecord, DenftError, FileRestored};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RestoreFile<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", file_record.uploader.as_ref()],
+        bump,
+        constraint = user_account.is_active @ DenftError::UserAccountInactive
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+
+    pub fn restore_file(ctx: Context<RestoreFile>) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let user_account = &mut ctx.accounts.user_account;
+        let clock = Clock::get()?;
+
+        require!(file_record.is_in_trash(clock.unix_timestamp), DenftError::FileNotInTrash);
+
+        // The file counts against the quota again, so it must still fit
+        require!(
+            user_account.can_add_file(file_record.file_size),
+            DenftError::StorageLimitExceeded
+        );
+
+        user_account.add_file(file_record.file_size);
+        file_record.restore();
+
+        emit_cpi!(FileRestored {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            restored_at: clock.unix_timestamp,
+            storage_used: ctx.accounts.user_account.storage_used,
+            file_count: ctx.accounts.user_account.file_count,
+        });
+
+        Ok(())
+    }
+}
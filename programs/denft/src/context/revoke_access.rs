@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use crate::{FileRecord, AccessPermission, DenftError, AccessRevoked};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RevokeAccess<'info> {
     #[account(
-        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
     )]
     pub file_record: Account<'info, FileRecord>,
     
@@ -20,10 +24,13 @@ pub struct RevokeAccess<'info> {
     )]
     pub access_permission: Account<'info, AccessPermission>,
     
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
     pub authority: Signer<'info>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
     use anchor_lang::solana_program::clock::Clock;
 
@@ -36,8 +43,9 @@ pub mod handler {
         // Use helper method to revoke access
         access_permission.revoke(clock.unix_timestamp);
 
-        emit!(AccessRevoked {
+        emit_cpi!(AccessRevoked {
             file_id: access_permission.file_record,
+            sequence: ctx.accounts.file_record.next_event_sequence(),
             owner: ctx.accounts.authority.key(),
             accessor: access_permission.accessor,
             revoked_at: clock.unix_timestamp,
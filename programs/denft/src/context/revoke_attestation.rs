@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, Attestation};
+use crate::errors::DenftError;
+use crate::events::AttestationRevoked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    #[account(
+        mut,
+        has_one = attester @ DenftError::Unauthorized,
+        seeds = [b"attestation", attestation.file_record.as_ref(), attester.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: The attested file; may already have been purged, so it is read only if still open
+    #[account(mut, address = attestation.file_record)]
+    pub file_record: UncheckedAccount<'info>,
+
+    pub attester: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        let attestation = &mut ctx.accounts.attestation;
+        let clock = Clock::get()?;
+
+        // Revoked attestations are kept on-chain so auditors can see the history
+        require!(!attestation.revoked, DenftError::AttestationAlreadyRevoked);
+        attestation.revoke(clock.unix_timestamp);
+
+        emit_cpi!(AttestationRevoked {
+            file_id: attestation.file_record,
+            sequence: FileRecord::next_event_sequence_of(&ctx.accounts.file_record)?,
+            attestation: attestation.key(),
+            attester: ctx.accounts.attester.key(),
+            revoked_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{Credential, CredentialStatusList};
+use crate::errors::DenftError;
+use crate::events::CredentialRevoked;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        mut,
+        has_one = issuer @ DenftError::Unauthorized,
+        seeds = [b"credential", issuer.key().as_ref(), credential.credential_hash.as_ref()],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(mut, address = credential.status_list)]
+    pub status_list: Box<Account<'info, CredentialStatusList>>,
+
+    pub issuer: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+        let credential = &mut ctx.accounts.credential;
+        let clock = Clock::get()?;
+
+        // The record stays on-chain so verifiers can tell revoked from never-issued
+        require!(!credential.revoked, DenftError::CredentialAlreadyRevoked);
+        credential.revoke(clock.unix_timestamp);
+        ctx.accounts.status_list.revoke(credential.status_index);
+
+        emit_cpi!(CredentialRevoked {
+            credential: credential.key(),
+            issuer: ctx.accounts.issuer.key(),
+            subject: credential.subject,
+            revoked_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
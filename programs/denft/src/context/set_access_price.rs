@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessListing, AccessPermission};
+use crate::errors::DenftError;
+use crate::events::AccessPriceSet;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAccessPrice<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Created on the first call, repriced on later ones
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AccessListing::LEN,
+        seeds = [b"listing", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_listing: Account<'info, AccessListing>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn set_access_price(
+        ctx: Context<SetAccessPrice>,
+        price: u64,
+        permissions: u8,
+        access_duration: Option<i64>,
+        max_downloads: Option<u32>,
+    ) -> Result<()> {
+        require!(price > 0, DenftError::InvalidPrice);
+        require!(
+            AccessPermission::is_valid_permission(permissions),
+            DenftError::InvalidPermissions
+        );
+        if let Some(duration) = access_duration {
+            require!(duration > 0, DenftError::InvalidExpirationTime);
+        }
+
+        let file_record = &mut ctx.accounts.file_record;
+        let access_listing = &mut ctx.accounts.access_listing;
+        let clock = Clock::get()?;
+
+        // Keep the sales count across repricing
+        if access_listing.file_record == Pubkey::default() {
+            access_listing.file_record = file_record.key();
+            access_listing.created_by = ctx.accounts.authority.key();
+            access_listing.sales = 0;
+            access_listing.reserved = [0; 16];
+        }
+
+        access_listing.price = price;
+        access_listing.permissions = permissions;
+        access_listing.access_duration = access_duration;
+        access_listing.max_downloads = max_downloads;
+        access_listing.updated_at = clock.unix_timestamp;
+
+        emit_cpi!(AccessPriceSet {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            access_listing: access_listing.key(),
+            owner: ctx.accounts.authority.key(),
+            price,
+            permissions,
+            access_duration,
+            max_downloads,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
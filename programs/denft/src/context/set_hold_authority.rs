@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::state::HoldConfig;
+use crate::errors::DenftError;
+use crate::events::HoldAuthorityUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetHoldAuthority<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = HoldConfig::LEN,
+        seeds = [b"hold_config"],
+        bump
+    )]
+    pub hold_config: Account<'info, HoldConfig>,
+
+    /// Only the program's upgrade authority may appoint the hold authority
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ DenftError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn set_hold_authority(ctx: Context<SetHoldAuthority>, hold_authority: Pubkey) -> Result<()> {
+        let hold_config = &mut ctx.accounts.hold_config;
+        let clock = Clock::get()?;
+
+        hold_config.hold_authority = hold_authority;
+        hold_config.updated_by = ctx.accounts.authority.key();
+        hold_config.updated_at = clock.unix_timestamp;
+
+        emit_cpi!(HoldAuthorityUpdated {
+            hold_authority,
+            updated_by: ctx.accounts.authority.key(),
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
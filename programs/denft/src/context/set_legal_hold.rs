@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{FileRecord, HoldConfig, DenftError, LegalHoldUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetLegalHold<'info> {
+    #[account(
+        mut,
+        // Token transfers cannot be blocked, so tokenized files are detokenized before locking
+        constraint = !file_record.is_tokenized() @ DenftError::FileTokenized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Holds are placed and released by the configured hold authority, never by the owner
+    #[account(
+        seeds = [b"hold_config"],
+        bump,
+        constraint = hold_config.hold_authority == authority.key() @ DenftError::Unauthorized
+    )]
+    pub hold_config: Account<'info, HoldConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+
+    pub fn set_legal_hold(ctx: Context<SetLegalHold>, legal_hold: bool) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        file_record.legal_hold = legal_hold;
+
+        emit_cpi!(LegalHoldUpdated {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            hold_authority: ctx.accounts.authority.key(),
+            legal_hold,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::state::ProtocolConfig;
+use crate::state::constants::MAX_PROTOCOL_FEE_BPS;
+use crate::errors::DenftError;
+use crate::events::ProtocolFeeUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Only the program's upgrade authority may change the fee
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ DenftError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn set_protocol_fee(ctx: Context<SetProtocolFee>, treasury: Pubkey, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= MAX_PROTOCOL_FEE_BPS, DenftError::InvalidProtocolFee);
+
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        let clock = Clock::get()?;
+
+        protocol_config.treasury = treasury;
+        protocol_config.fee_bps = fee_bps;
+        protocol_config.updated_by = ctx.accounts.authority.key();
+        protocol_config.updated_at = clock.unix_timestamp;
+        protocol_config.reserved = [0; 32];
+
+        emit_cpi!(ProtocolFeeUpdated {
+            treasury,
+            fee_bps,
+            updated_by: ctx.accounts.authority.key(),
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, TokenGate, TokenGateKind, AccessPermission};
+use crate::errors::DenftError;
+use crate::events::TokenGateSet;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTokenGate<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TokenGate::LEN,
+        seeds = [b"token_gate", file_record.key().as_ref()],
+        bump
+    )]
+    pub token_gate: Account<'info, TokenGate>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn set_token_gate(ctx: Context<SetTokenGate>, kind: TokenGateKind, permissions: u8) -> Result<()> {
+        require!(kind.is_valid(), DenftError::InvalidTokenGate);
+        require!(
+            AccessPermission::is_valid_permission(permissions),
+            DenftError::InvalidPermissions
+        );
+
+        let file_record = &mut ctx.accounts.file_record;
+        let token_gate = &mut ctx.accounts.token_gate;
+        let clock = Clock::get()?;
+
+        token_gate.file_record = file_record.key();
+        token_gate.kind = kind;
+        token_gate.permissions = permissions;
+        token_gate.created_by = ctx.accounts.authority.key();
+        token_gate.created_at = clock.unix_timestamp;
+        token_gate.reserved = [0; 16];
+
+        file_record.has_token_gate = true;
+
+        emit_cpi!(TokenGateSet {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            token_gate: token_gate.key(),
+            owner: ctx.accounts.authority.key(),
+            kind,
+            permissions,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
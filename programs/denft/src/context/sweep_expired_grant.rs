@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{FileRecord, AccessPermission, DenftError, AccessGrantSwept};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepExpiredGrant<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"access",
+            access_permission.file_record.as_ref(),
+            access_permission.accessor.as_ref()
+        ],
+        bump,
+        has_one = payer @ DenftError::Unauthorized,
+        // Grants created before payers were recorded cannot say who should get the rent back
+        constraint = access_permission.payer != Pubkey::default() @ DenftError::UnknownRentPayer
+    )]
+    pub access_permission: Account<'info, AccessPermission>,
+
+    /// CHECK: The granted file; may already have been purged, so it is read only if still open
+    #[account(mut, address = access_permission.file_record)]
+    pub file_record: UncheckedAccount<'info>,
+
+    /// CHECK: Original rent payer recorded in the permission, receives the rent back
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+
+    pub fn sweep_expired_grant(ctx: Context<SweepExpiredGrant>) -> Result<()> {
+        let access_permission = &ctx.accounts.access_permission;
+        let clock = Clock::get()?;
+
+        // Grants left behind by a purged file, or predating a re-upload, can never be used either
+        let orphaned = match FileRecord::load_open(&ctx.accounts.file_record)? {
+            Some(file_record) => access_permission.is_stale(&file_record),
+            None => true,
+        };
+
+        // Anyone may sweep, but only grants that can never be used again
+        require!(
+            !access_permission.is_active || access_permission.is_expired(clock.unix_timestamp) || orphaned,
+            DenftError::AccessStillValid
+        );
+
+        emit_cpi!(AccessGrantSwept {
+            file_id: access_permission.file_record,
+            sequence: FileRecord::next_event_sequence_of(&ctx.accounts.file_record)?,
+            access_permission: access_permission.key(),
+            accessor: access_permission.accessor,
+            payer: access_permission.payer,
+            swept_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
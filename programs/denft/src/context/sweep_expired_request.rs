@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::{FileRecord, AccessRequest};
+use crate::errors::DenftError;
+use crate::events::AccessRequestSwept;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepExpiredRequest<'info> {
+    #[account(
+        mut,
+        close = requester,
+        seeds = [
+            b"access_request",
+            access_request.file_record.as_ref(),
+            requester.key().as_ref()
+        ],
+        bump,
+        has_one = requester @ DenftError::InvalidAccessPermission
+    )]
+    pub access_request: Account<'info, AccessRequest>,
+
+    /// CHECK: The requested file; may already have been purged, so it is read only if still open
+    #[account(mut, address = access_request.file_record)]
+    pub file_record: UncheckedAccount<'info>,
+
+    /// CHECK: Requester recorded in the access request, receives its rent back
+    #[account(mut)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn sweep_expired_request(ctx: Context<SweepExpiredRequest>) -> Result<()> {
+        let access_request = &ctx.accounts.access_request;
+        let clock = Clock::get()?;
+
+        // Anyone may sweep, but only requests that can no longer be approved
+        require!(access_request.is_expired(clock.unix_timestamp), DenftError::AccessRequestNotExpired);
+
+        emit_cpi!(AccessRequestSwept {
+            file_id: access_request.file_record,
+            sequence: FileRecord::next_event_sequence_of(&ctx.accounts.file_record)?,
+            requester: access_request.requester,
+            expires_at: access_request.expires_at,
+            swept_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+
+use crate::state::FileRecord;
+use crate::errors::DenftError;
+use crate::events::FileTokenized;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TokenizeFile<'info> {
+    #[account(
+        mut,
+        seeds = [b"file", file_record.uploader.as_ref(), file_record.file_hash.as_ref()],
+        bump,
+        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal,
+        constraint = !file_record.is_tokenized() @ DenftError::FileTokenized
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Fresh mint keypair; the file record is its mint authority until supply is fixed at 1
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = file_record
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = authority
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn tokenize_file(ctx: Context<TokenizeFile>) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(ctx.accounts.file_record.is_accessible(), DenftError::FileNotActive);
+        ctx.accounts.file_record.check_not_locked(clock.unix_timestamp)?;
+
+        let uploader = ctx.accounts.file_record.uploader;
+        let file_hash = ctx.accounts.file_record.file_hash;
+        let bump = [ctx.bumps.file_record];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"file", uploader.as_ref(), file_hash.as_ref(), &bump]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.file_record.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        // Drop the mint authority so the supply stays at exactly one
+        token::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.file_record.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        let file_record = &mut ctx.accounts.file_record;
+        file_record.ownership_mint = Some(ctx.accounts.mint.key());
+
+        emit_cpi!(FileTokenized {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            mint: ctx.accounts.mint.key(),
+            holder: ctx.accounts.authority.key(),
+            tokenized_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
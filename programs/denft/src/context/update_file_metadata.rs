@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::FileRecord;
+use crate::state::constants::{MAX_ENCRYPTED_METADATA_LENGTH, MAX_CONTENT_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH};
+use crate::errors::DenftError;
+use crate::events::FileMetadataUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(description: String, content_type: String, encrypted_metadata: String)]
+pub struct UpdateFileMetadata<'info> {
+    #[account(
+        mut,
+        realloc = FileRecord::space_for(
+            file_record.ipfs_hash.len(),
+            encrypted_metadata.len(),
+            content_type.len(),
+            description.len()
+        ),
+        realloc::payer = authority,
+        realloc::zero = false,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn update_file_metadata(
+        ctx: Context<UpdateFileMetadata>,
+        description: String,
+        content_type: String,
+        encrypted_metadata: String,
+    ) -> Result<()> {
+        require!(content_type.len() <= MAX_CONTENT_TYPE_LENGTH, DenftError::ContentTypeTooLong);
+        require!(description.len() <= MAX_DESCRIPTION_LENGTH, DenftError::DescriptionTooLong);
+        require!(
+            encrypted_metadata.len() <= MAX_ENCRYPTED_METADATA_LENGTH,
+            DenftError::EncryptedMetadataTooLong
+        );
+
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        require!(file_record.is_accessible(), DenftError::FileNotActive);
+        file_record.check_not_locked(clock.unix_timestamp)?;
+
+        // The account has already been resized to fit; growing charged rent, shrinking refunded it
+        file_record.description = description;
+        file_record.content_type = content_type;
+        file_record.encrypted_metadata = encrypted_metadata;
+
+        emit_cpi!(FileMetadataUpdated {
+            file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            account_size: file_record.to_account_info().data_len() as u64,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,18 +1,24 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use crate::{FileRecord, DenftError, FilePublicityUpdated};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateFilePublicity<'info> {
     #[account(
         mut,
-        constraint = file_record.owner == authority.key() @ DenftError::Unauthorized
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
     )]
     pub file_record: Account<'info, FileRecord>,
     
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
     pub authority: Signer<'info>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
     use anchor_lang::solana_program::clock::Clock;
 
@@ -22,11 +28,17 @@ pub mod handler {
     ) -> Result<()> {
         let file_record = &mut ctx.accounts.file_record;
         let clock = Clock::get()?;
+
+        // Locked records can be made public but not taken private again
+        if file_record.is_public_verification && !is_public {
+            file_record.check_not_locked(clock.unix_timestamp)?;
+        }
         
         file_record.is_public_verification = is_public;
 
-        emit!(FilePublicityUpdated {
+        emit_cpi!(FilePublicityUpdated {
             file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
             owner: ctx.accounts.authority.key(),
             is_public,
             updated_at: clock.unix_timestamp,
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{FileRecord, DenftError, FileReleaseUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFileRelease<'info> {
+    #[account(
+        mut,
+        constraint = file_record.is_owned_by(&authority.key(), owner_token_account.as_deref()) @ DenftError::Unauthorized,
+        constraint = !file_record.co_owned @ DenftError::CoOwnedFileRequiresProposal
+    )]
+    pub file_record: Account<'info, FileRecord>,
+
+    /// Holder's token account, required while the file is tokenized
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+    use anchor_lang::solana_program::clock::Clock;
+
+    pub fn update_file_release(
+        ctx: Context<UpdateFileRelease>,
+        not_before: Option<i64>,
+    ) -> Result<()> {
+        let file_record = &mut ctx.accounts.file_record;
+        let clock = Clock::get()?;
+
+        // Locked records can be released sooner but not hidden from verification for longer
+        if let Some(new_not_before) = not_before {
+            let later_than_current = match file_record.not_before {
+                Some(current) => new_not_before > current,
+                None => true,
+            };
+            let delays_release = later_than_current && new_not_before > clock.unix_timestamp;
+            if delays_release {
+                file_record.check_not_locked(clock.unix_timestamp)?;
+            }
+        }
+
+        file_record.not_before = not_before;
+
+        emit_cpi!(FileReleaseUpdated {
+            file_id: ctx.accounts.file_record.key(),
+            sequence: ctx.accounts.file_record.next_event_sequence(),
+            owner: ctx.accounts.authority.key(),
+            not_before,
+            updated_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
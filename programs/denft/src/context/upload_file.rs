@@ -1,15 +1,25 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
-use anchor_lang::emit;
 
-use crate::state::{UserAccount, FileRecord};
-use crate::state::constants::{MAX_FILE_SIZE, MAX_IPFS_HASH_LENGTH, MAX_CONTENT_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH};
+use crate::state::{UserAccount, FileRecord, ContentRegistry, FileTombstone};
+use crate::state::constants::{
+    MAX_FILE_SIZE, MAX_IPFS_HASH_LENGTH, MAX_ENCRYPTED_METADATA_LENGTH, MAX_CONTENT_TYPE_LENGTH,
+    MAX_DESCRIPTION_LENGTH, CURRENT_ACCOUNT_VERSION,
+};
 use crate::errors::DenftError;
 use crate::events::FileUploaded;
 use crate::generate_verification_id::generate_verification_id;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(file_hash: [u8; 32])]
+#[instruction(
+    file_hash: [u8; 32],
+    ipfs_hash: String,
+    encrypted_metadata: String,
+    file_size: u64,
+    content_type: String,
+    description: String
+)]
 pub struct UploadFile<'info> {
     #[account(
         mut,
@@ -22,7 +32,12 @@ pub struct UploadFile<'info> {
     #[account(
         init,
         payer = authority,
-        space = FileRecord::space_required(), // Extra space for metadata
+        space = FileRecord::space_for(
+            ipfs_hash.len(),
+            encrypted_metadata.len(),
+            content_type.len(),
+            description.len()
+        ),
         seeds = [
             b"file",
             authority.key().as_ref(),
@@ -31,6 +46,23 @@ pub struct UploadFile<'info> {
         bump
     )]
     pub file_record: Account<'info, FileRecord>,
+
+    /// Global first-seen record for the hash, shared by every uploader
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ContentRegistry::LEN,
+        seeds = [b"content", file_hash.as_ref()],
+        bump
+    )]
+    pub content_registry: Account<'info, ContentRegistry>,
+
+    /// CHECK: Tombstone of an earlier record at this address, read only if it exists
+    #[account(
+        seeds = [b"tombstone", file_record.key().as_ref()],
+        bump
+    )]
+    pub file_tombstone: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -38,7 +70,7 @@ pub struct UploadFile<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
 
     pub fn upload_file(
@@ -53,6 +85,10 @@ pub mod handler {
         // Validate input parameters
         require!(file_size > 0 && file_size <= MAX_FILE_SIZE, DenftError::InvalidFileSize);
         require!(ipfs_hash.len() <= MAX_IPFS_HASH_LENGTH, DenftError::IpfsHashTooLong);
+        require!(
+            encrypted_metadata.len() <= MAX_ENCRYPTED_METADATA_LENGTH,
+            DenftError::EncryptedMetadataTooLong
+        );
         require!(content_type.len() <= MAX_CONTENT_TYPE_LENGTH, DenftError::ContentTypeTooLong);
         require!(description.len() <= MAX_DESCRIPTION_LENGTH, DenftError::DescriptionTooLong);
 
@@ -78,19 +114,42 @@ pub mod handler {
         file_record.is_active = true;
         file_record.deleted_at = None;
         file_record.verification_id = generate_verification_id(&file_hash, &clock.unix_timestamp);
-        file_record.reserved = [0; 32];
+        file_record.co_owned = false;
+        file_record.not_before = None;
+        file_record.has_access_log = false;
+        file_record.retention_until = None;
+        file_record.legal_hold = false;
+        file_record.uploader = ctx.accounts.authority.key();
+        file_record.ownership_mint = None;
+        file_record.has_token_gate = false;
+        file_record.version = CURRENT_ACCOUNT_VERSION;
+        // A re-upload after a purge carries on from the purged record's last event
+        file_record.event_sequence = FileTombstone::load(&ctx.accounts.file_tombstone)?
+            .map_or(0, |tombstone| tombstone.last_sequence);
+        file_record.reserved = [0; 1];
 
         // Update user statistics using helper method
         user_account.add_file(file_size);
 
-        emit!(FileUploaded {
+        ctx.accounts.content_registry.register(
+            file_hash,
+            ctx.accounts.authority.key(),
+            file_record.key(),
+            clock.unix_timestamp,
+            clock.slot,
+        );
+
+        emit_cpi!(FileUploaded {
             file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
             owner: ctx.accounts.authority.key(),
             file_hash,
             ipfs_hash: file_record.ipfs_hash.clone(),
             file_size,
             timestamp: clock.unix_timestamp,
             verification_id: file_record.verification_id,
+            storage_used: user_account.storage_used,
+            file_count: user_account.file_count,
         });
 
         Ok(())
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::clock::Clock;
+
+use crate::state::ExistenceProof;
+use crate::errors::DenftError;
+use crate::events::ExistenceProofVerified;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(file_hash: [u8; 32])]
+pub struct VerifyExistenceProof<'info> {
+    #[account(
+        seeds = [b"proof", existence_proof.owner.as_ref(), file_hash.as_ref()],
+        bump
+    )]
+    pub existence_proof: Account<'info, ExistenceProof>,
+
+    pub verifier: Signer<'info>,
+}
+
+pub(crate) mod handler {
+    use super::*;
+
+    pub fn verify_existence_proof(ctx: Context<VerifyExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+        let existence_proof = &ctx.accounts.existence_proof;
+        let clock = Clock::get()?;
+
+        require!(existence_proof.verify_hash(&file_hash), DenftError::FileHashMismatch);
+
+        emit_cpi!(ExistenceProofVerified {
+            proof: existence_proof.key(),
+            owner: existence_proof.owner,
+            verifier: ctx.accounts.verifier.key(),
+            file_hash,
+            slot: existence_proof.slot,
+            timestamp: existence_proof.timestamp,
+            verified_at: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
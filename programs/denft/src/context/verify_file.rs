@@ -1,12 +1,13 @@
 // program/denft/src/context/verify_file.rs
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use anchor_lang::solana_program::clock::Clock;
-use anchor_lang::emit;
 
-use crate::state::FileRecord;
+use crate::state::{FileRecord, AccessLog, AccessLogEntry, LoggedAccessType, Attestation, TokenGate, ContentRegistry};
 use crate::errors::DenftError;
 use crate::events::FileVerified;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(file_hash: [u8; 32])]
 pub struct VerifyFile<'info> {
@@ -14,17 +15,49 @@ pub struct VerifyFile<'info> {
         mut,
         seeds = [
             b"file",
-            file_record.owner.as_ref(),
+            file_record.uploader.as_ref(),
             file_hash.as_ref()
         ],
         bump
     )]
     pub file_record: Account<'info, FileRecord>,
+
+    /// Required when the file keeps an on-chain access log
+    #[account(
+        mut,
+        seeds = [b"access_log", file_record.key().as_ref()],
+        bump
+    )]
+    pub access_log: Option<Account<'info, AccessLog>>,
+
+    /// Holder's token account, lets the holder of a tokenized file verify before release
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Restricts verification of private gated files to token holders
+    #[account(
+        seeds = [b"token_gate", file_record.key().as_ref()],
+        bump
+    )]
+    pub token_gate: Option<Account<'info, TokenGate>>,
+
+    /// Caller's token account for the gate's mint or collection NFT
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata of the gating NFT, validated by the token gate
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// First-seen record for the hash, reported in the verification output
+    #[account(
+        seeds = [b"content", file_hash.as_ref()],
+        bump
+    )]
+    pub content_registry: Option<Account<'info, ContentRegistry>>,
     
     pub authority: Signer<'info>,
+    // remaining_accounts: Attestation accounts for this file to include in the output
 }
 
-pub mod handler {
+pub(crate) mod handler {
     use super::*;
 
     pub fn verify_file(ctx: Context<VerifyFile>, file_hash: [u8; 32]) -> Result<()> {
@@ -35,16 +68,87 @@ pub mod handler {
         require!(file_record.verify_hash(&file_hash), DenftError::FileHashMismatch);
         require!(file_record.is_accessible(), DenftError::FileNotActive);
 
+        let is_owner = file_record.is_owned_by(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.owner_token_account.as_deref()
+        );
+
+        // Only the owner can verify a time-locked file before its release
+        require!(
+            file_record.is_released(clock.unix_timestamp) || is_owner,
+            DenftError::AccessNotYetValid
+        );
+
+        // Private gated files can only be verified by token holders
+        if file_record.has_token_gate && !file_record.is_public_verification && !is_owner {
+            let token_gate = ctx
+                .accounts
+                .token_gate
+                .as_ref()
+                .ok_or(DenftError::TokenGateNotSatisfied)?;
+
+            token_gate.check_holder(
+                &ctx.accounts.authority.key(),
+                ctx.accounts.gate_token_account.as_deref(),
+                ctx.accounts.gate_metadata.as_deref(),
+            )?;
+        }
+
         // Update access statistics using helper method
         file_record.increment_access();
 
-        emit!(FileVerified {
+        if file_record.has_access_log {
+            let access_log = ctx
+                .accounts
+                .access_log
+                .as_mut()
+                .ok_or(DenftError::AccessLogRequired)?;
+
+            access_log.record(AccessLogEntry {
+                accessor: ctx.accounts.authority.key(),
+                access_type: LoggedAccessType::Verify,
+                timestamp: clock.unix_timestamp,
+                slot: clock.slot,
+            });
+        }
+
+        // Report attestations that are still current; revoked or expired ones are skipped
+        let mut attestations = Vec::new();
+        for (i, account_info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(account_info.owner == &crate::ID, DenftError::InvalidAttestation);
+
+            // Each attestation is counted once, however many times it is passed
+            require!(
+                !ctx.remaining_accounts[..i].iter().any(|other| other.key == account_info.key),
+                DenftError::DuplicateAttestation
+            );
+
+            let attestation = Attestation::try_deserialize(&mut &account_info.data.borrow()[..])?;
+            require!(
+                attestation.file_record == file_record.key(),
+                DenftError::InvalidAttestation
+            );
+            if attestation.is_current(clock.unix_timestamp) {
+                attestations.push(attestation.summary());
+            }
+        }
+
+        emit_cpi!(FileVerified {
             file_id: file_record.key(),
+            sequence: file_record.next_event_sequence(),
             verifier: ctx.accounts.authority.key(),
             file_hash,
             verified_at: clock.unix_timestamp,
             original_timestamp: file_record.timestamp,
             verification_id: file_record.verification_id,
+            attestations,
+            first_uploader: ctx.accounts.content_registry.as_ref().map(|registry| registry.first_uploader),
+            first_seen_at: ctx.accounts.content_registry.as_ref().map(|registry| registry.first_timestamp),
+            subsequent_registrations: ctx
+                .accounts
+                .content_registry
+                .as_ref()
+                .map_or(0, |registry| registry.subsequent_registrations),
         });
 
         Ok(())
@@ -57,4 +57,190 @@ pub enum DenftError {
 
   #[msg("Invalid access permission.")]
   InvalidAccessPermission,
+
+  #[msg("Invalid co-owner set. Requires 2 to 10 unique owners including the current owner, and a threshold between 1 and the owner count.")]
+  InvalidCoOwners,
+
+  #[msg("File is already co-owned.")]
+  FileAlreadyCoOwned,
+
+  #[msg("File is co-owned. This operation requires an approved proposal.")]
+  CoOwnedFileRequiresProposal,
+
+  #[msg("Signer is not a co-owner of this file.")]
+  NotCoOwner,
+
+  #[msg("Proposal already approved by this co-owner.")]
+  ProposalAlreadyApproved,
+
+  #[msg("Proposal already executed.")]
+  ProposalAlreadyExecuted,
+
+  #[msg("Proposal has not reached the approval threshold.")]
+  ProposalThresholdNotMet,
+
+  #[msg("Proposal action does not match this instruction.")]
+  ProposalActionMismatch,
+
+  #[msg("Access request has expired.")]
+  AccessRequestExpired,
+
+  #[msg("Group name too long. Maximum 32 characters.")]
+  GroupNameTooLong,
+
+  #[msg("Group membership does not match the access permission.")]
+  InvalidGroupMembership,
+
+  #[msg("Claim key does not match the share link.")]
+  InvalidClaimKey,
+
+  #[msg("Share link has expired.")]
+  ClaimExpired,
+
+  #[msg("Invalid access window. Start time must be before expiration.")]
+  InvalidAccessWindow,
+
+  #[msg("Access is not yet valid.")]
+  AccessNotYetValid,
+
+  #[msg("Invalid rate limit. Downloads per window and window length must be greater than zero.")]
+  InvalidRateLimit,
+
+  #[msg("Download rate limit reached for the current window.")]
+  DownloadRateLimited,
+
+  #[msg("Access permission does not include read.")]
+  MissingReadPermission,
+
+  #[msg("Access permission does not include download.")]
+  MissingDownloadPermission,
+
+  #[msg("Access permission does not include share.")]
+  MissingSharePermission,
+
+  #[msg("Access permission is still active and has not expired.")]
+  AccessStillValid,
+
+  #[msg("Invalid access log capacity. Must be between 1 and 200 entries.")]
+  InvalidAccessLogCapacity,
+
+  #[msg("This file keeps an access log, which must be provided.")]
+  AccessLogRequired,
+
+  #[msg("File is not in the trash or its retention period has ended.")]
+  FileNotInTrash,
+
+  #[msg("File is still within its trash retention period.")]
+  FileNotPurgeable,
+
+  #[msg("File is under a retention lock.")]
+  FileUnderRetention,
+
+  #[msg("File is under legal hold.")]
+  FileUnderLegalHold,
+
+  #[msg("Retention can only be extended, never shortened.")]
+  RetentionCannotBeShortened,
+
+  #[msg("Claim type is too long.")]
+  ClaimTypeTooLong,
+
+  #[msg("Attestation has already been revoked.")]
+  AttestationAlreadyRevoked,
+
+  #[msg("Attestation does not belong to this file.")]
+  InvalidAttestation,
+
+  #[msg("Preceding instruction is not a valid ed25519 signature check.")]
+  InvalidSignatureInstruction,
+
+  #[msg("Signature does not match the expected signer and file hash.")]
+  SignatureMismatch,
+
+  #[msg("Schema identifier is too long.")]
+  SchemaIdTooLong,
+
+  #[msg("Credential has already been revoked.")]
+  CredentialAlreadyRevoked,
+
+  #[msg("File is tokenized; detokenize it first.")]
+  FileTokenized,
+
+  #[msg("File is not tokenized.")]
+  FileNotTokenized,
+
+  #[msg("Invalid token gate.")]
+  InvalidTokenGate,
+
+  #[msg("Caller does not satisfy the file's token gate.")]
+  TokenGateNotSatisfied,
+
+  #[msg("Encrypted metadata is too long.")]
+  EncryptedMetadataTooLong,
+
+  #[msg("Account is already at the current layout version.")]
+  AccountAlreadyMigrated,
+
+  #[msg("File is still active and cannot be reactivated.")]
+  FileStillActive,
+
+  #[msg("Price must be greater than zero.")]
+  InvalidPrice,
+
+  #[msg("Listing price is higher than the buyer's maximum.")]
+  PriceExceedsMaximum,
+
+  #[msg("Protocol fee exceeds the maximum.")]
+  InvalidProtocolFee,
+
+  #[msg("Treasury does not match the protocol config.")]
+  InvalidTreasury,
+
+  #[msg("Escrow needs a positive amount, a future deadline and a positive dispute window.")]
+  InvalidEscrowTerms,
+
+  #[msg("Escrow delivery deadline has passed.")]
+  EscrowDeadlinePassed,
+
+  #[msg("Escrow has already been delivered.")]
+  EscrowAlreadyDelivered,
+
+  #[msg("Escrow has not been delivered or its dispute window is still open.")]
+  EscrowNotClaimable,
+
+  #[msg("Escrow can only be refunded when nothing was delivered by the deadline, or a dispute went unresolved.")]
+  EscrowNotRefundable,
+
+  #[msg("Escrow does not match this file or access.")]
+  InvalidEscrow,
+
+  #[msg("File still has dependent accounts; close them before purging.")]
+  FileHasDependents,
+
+  #[msg("Account was created for an earlier upload of this file.")]
+  StaleFileReference,
+
+  #[msg("The same attestation was passed more than once.")]
+  DuplicateAttestation,
+
+  #[msg("Credential status list is full; issue into a new list.")]
+  StatusListFull,
+
+  #[msg("Grant predates rent payer tracking and cannot be swept.")]
+  UnknownRentPayer,
+
+  #[msg("File has an active access listing; remove it first.")]
+  FileListedForSale,
+
+  #[msg("Escrow can only be disputed once, after delivery and inside the dispute window.")]
+  EscrowNotDisputable,
+
+  #[msg("Escrow is disputed; its funds are frozen until the arbiter settles it.")]
+  EscrowDisputed,
+
+  #[msg("Escrow is not disputed.")]
+  EscrowNotDisputed,
+
+  #[msg("Access request has not expired yet.")]
+  AccessRequestNotExpired,
 }
\ No newline at end of file
@@ -1,7 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{ProposalAction, AttestationSummary, TokenGateKind, AccessType};
+
 
 // Events for indexing and monitoring
+//
+// Events about a file carry its `file_id` and a `sequence` that increases by one per event,
+// across restores, reactivations and re-uploads after a purge. Events about a file that has
+// already been purged report sequence 0. Events about users, groups, proofs, credentials,
+// escrows and protocol settings are not sequenced; order those by slot and transaction index.
 #[event]
 pub struct UserInitialized {
     pub user: Pubkey,
@@ -11,27 +18,33 @@ pub struct UserInitialized {
 #[event]
 pub struct FileUploaded {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub owner: Pubkey,
     pub file_hash: [u8; 32],
     pub ipfs_hash: String,
     pub file_size: u64,
     pub timestamp: i64,
     pub verification_id: u64,
+    pub storage_used: u64,
+    pub file_count: u32,
 }
 
 #[event]
 pub struct AccessGranted {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub owner: Pubkey,
     pub accessor: Pubkey,
     pub permissions: u8,
     pub granted_at: i64,
     pub expires_at: Option<i64>,
+    pub not_before: Option<i64>,
 }
 
 #[event]
 pub struct AccessRevoked {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub owner: Pubkey,
     pub accessor: Pubkey,
     pub revoked_at: i64,
@@ -40,11 +53,16 @@ pub struct AccessRevoked {
 #[event]
 pub struct FileVerified {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub verifier: Pubkey,
     pub file_hash: [u8; 32],
     pub verified_at: i64,
     pub original_timestamp: i64,
     pub verification_id: u64,
+    pub attestations: Vec<AttestationSummary>,
+    pub first_uploader: Option<Pubkey>,
+    pub first_seen_at: Option<i64>,
+    pub subsequent_registrations: u64,
 }
 
 
@@ -52,22 +70,488 @@ pub struct FileVerified {
 #[event]
 pub struct FileAccessed {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub accessor: Pubkey,
-    pub access_type: u8,
+    pub access_type: AccessType,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct FileDeleted {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub owner: Pubkey,
     pub deleted_at: i64,
+    pub storage_used: u64,
+    pub file_count: u32,
 }
 
 #[event]
 pub struct FilePublicityUpdated {
     pub file_id: Pubkey,
+    pub sequence: u64,
     pub owner: Pubkey,
     pub is_public: bool,
     pub updated_at: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct CoOwnershipEnabled {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owners: Vec<Pubkey>,
+    pub threshold: u8,
+    pub enabled_at: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub approved_at: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub executed_at: i64,
+}
+
+#[event]
+pub struct AccessRequested {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    // Stale while the file is tokenized; the holder of `ownership_mint` approves instead
+    pub owner: Pubkey,
+    pub ownership_mint: Option<Pubkey>,
+    pub requester: Pubkey,
+    pub permissions: u8,
+    pub message_hash: [u8; 32],
+    pub requested_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct AccessRequestApproved {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub requester: Pubkey,
+    pub permissions: u8,
+    pub approved_at: i64,
+}
+
+#[event]
+pub struct AccessRequestDenied {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub requester: Pubkey,
+    pub denied_at: i64,
+}
+
+#[event]
+pub struct AccessRequestCancelled {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub requester: Pubkey,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct AccessRequestSwept {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub requester: Pubkey,
+    pub expires_at: i64,
+    pub swept_at: i64,
+}
+
+#[event]
+pub struct GroupCreated {
+    pub group: Pubkey,
+    pub admin: Pubkey,
+    pub name: String,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct GroupMemberAdded {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+}
+
+#[event]
+pub struct GroupMemberRemoved {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub removed_by: Pubkey,
+    pub removed_at: i64,
+}
+
+#[event]
+pub struct ClaimableAccessCreated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub claimable_grant: Pubkey,
+    pub owner: Pubkey,
+    pub permissions: u8,
+    pub claim_expires_at: i64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct AccessClaimed {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub claimable_grant: Pubkey,
+    pub owner: Pubkey,
+    pub accessor: Pubkey,
+    pub permissions: u8,
+    pub claimed_at: i64,
+}
+
+#[event]
+pub struct ClaimableAccessCancelled {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub claimable_grant: Pubkey,
+    pub owner: Pubkey,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct FileReleaseUpdated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub not_before: Option<i64>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AccessGrantSwept {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub access_permission: Pubkey,
+    pub accessor: Pubkey,
+    pub payer: Pubkey,
+    pub swept_at: i64,
+}
+
+#[event]
+pub struct AccessLogResized {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub access_log: Pubkey,
+    pub owner: Pubkey,
+    pub capacity: u16,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct FileRestored {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub restored_at: i64,
+    pub storage_used: u64,
+    pub file_count: u32,
+}
+
+#[event]
+pub struct FilePurged {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub purged_at: i64,
+}
+
+#[event]
+pub struct RetentionExtended {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub previous_retention_until: Option<i64>,
+    pub retention_until: i64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct LegalHoldUpdated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub hold_authority: Pubkey,
+    pub legal_hold: bool,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct HoldAuthorityUpdated {
+    pub hold_authority: Pubkey,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct ExistenceProofCreated {
+    pub proof: Pubkey,
+    pub owner: Pubkey,
+    pub file_hash: [u8; 32],
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExistenceProofVerified {
+    pub proof: Pubkey,
+    pub owner: Pubkey,
+    pub verifier: Pubkey,
+    pub file_hash: [u8; 32],
+    pub slot: u64,
+    pub timestamp: i64,
+    pub verified_at: i64,
+}
+
+#[event]
+pub struct FileAttested {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub attestation: Pubkey,
+    pub attester: Pubkey,
+    pub file_hash: [u8; 32],
+    pub claim_type: String,
+    pub expires_at: Option<i64>,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct AttestationRevoked {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub attestation: Pubkey,
+    pub attester: Pubkey,
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct DocumentSigned {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub signer: Pubkey,
+    pub file_hash: [u8; 32],
+    pub signature: [u8; 64],
+    pub recorded_by: Pubkey,
+    pub recorded_at: i64,
+}
+
+#[event]
+pub struct CredentialIssued {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub subject: Pubkey,
+    pub credential_hash: [u8; 32],
+    pub schema_id: String,
+    pub expires_at: Option<i64>,
+    pub status_list: Pubkey,
+    pub status_index: u32,
+    pub issued_at: i64,
+}
+
+#[event]
+pub struct CredentialRevoked {
+    pub credential: Pubkey,
+    pub issuer: Pubkey,
+    pub subject: Pubkey,
+    pub revoked_at: i64,
+}
+
+#[event]
+pub struct FileTokenized {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub tokenized_at: i64,
+}
+
+#[event]
+pub struct FileDetokenized {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub detokenized_at: i64,
+}
+
+#[event]
+pub struct TokenGateSet {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub token_gate: Pubkey,
+    pub owner: Pubkey,
+    pub kind: TokenGateKind,
+    pub permissions: u8,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct TokenGateRemoved {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub token_gate: Pubkey,
+    pub owner: Pubkey,
+    pub removed_at: i64,
+}
+
+#[event]
+pub struct FileMetadataUpdated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub account_size: u64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub account_size: u64,
+    pub migrated_by: Pubkey,
+    pub migrated_at: i64,
+}
+
+#[event]
+pub struct FileReactivated {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub owner: Pubkey,
+    pub previous_verification_id: u64,
+    pub verification_id: u64,
+    pub previous_deleted_at: Option<i64>,
+    pub previous_access_count: u64,
+    pub previous_download_count: u64,
+    pub storage_used: u64,
+    pub file_count: u32,
+    pub reactivated_at: i64,
+}
+
+#[event]
+pub struct ProtocolFeeUpdated {
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AccessPriceSet {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub access_listing: Pubkey,
+    pub owner: Pubkey,
+    pub price: u64,
+    pub permissions: u8,
+    pub access_duration: Option<i64>,
+    pub max_downloads: Option<u32>,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct AccessPriceRemoved {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub access_listing: Pubkey,
+    pub owner: Pubkey,
+    pub sales: u64,
+    pub removed_at: i64,
+}
+
+#[event]
+pub struct AccessPurchased {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub access_listing: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub purchased_at: i64,
+}
+
+#[event]
+pub struct EscrowCreated {
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub arbiter: Pubkey,
+    pub file_hash: [u8; 32],
+    pub amount: u64,
+    pub deadline: i64,
+    pub dispute_window: i64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct EscrowDelivered {
+    pub file_id: Pubkey,
+    pub sequence: u64,
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub delivered_at: i64,
+    pub disputable_until: i64,
+}
+
+#[event]
+pub struct EscrowDisputed {
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub arbiter: Pubkey,
+    pub file_hash: [u8; 32],
+    pub disputed_at: i64,
+}
+
+#[event]
+pub struct EscrowReleased {
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub file_hash: [u8; 32],
+    pub amount: u64,
+    pub released_by: Pubkey,
+    pub released_at: i64,
+}
+
+#[event]
+pub struct EscrowRefunded {
+    pub escrow: Pubkey,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub file_hash: [u8; 32],
+    pub amount: u64,
+    pub refunded_at: i64,
+}
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::AddGroupMember;
+
+pub fn handler(ctx: Context<AddGroupMember>) -> Result<()> {
+    crate::context::add_group_member::handler::add_group_member(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ApproveProposal;
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    crate::context::approve_proposal::handler::approve_proposal(ctx)
+}
\ No newline at end of file
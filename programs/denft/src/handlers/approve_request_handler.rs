@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::{context::ApproveRequest, DownloadRateLimit};
+
+pub fn handler(
+    ctx: Context<ApproveRequest>,
+    expires_at: Option<i64>,
+    max_downloads: Option<u32>,
+    not_before: Option<i64>,
+    rate_limit: Option<DownloadRateLimit>,
+) -> Result<()> {
+    crate::context::approve_request::handler::approve_request(
+        ctx,
+        expires_at,
+        max_downloads,
+        not_before,
+        rate_limit,
+    )
+}
\ No newline at end of file
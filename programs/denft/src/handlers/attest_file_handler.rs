@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use crate::context::AttestFile;
+
+pub fn handler(
+    ctx: Context<AttestFile>,
+    file_hash: [u8; 32],
+    claim_type: String,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    crate::context::attest_file::handler::attest_file(
+        ctx,
+        file_hash,
+        claim_type,
+        expires_at,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::CancelClaimableAccess;
+
+pub fn handler(ctx: Context<CancelClaimableAccess>) -> Result<()> {
+    crate::context::cancel_claimable_access::handler::cancel_claimable_access(ctx)
+}
\ No newline at end of file
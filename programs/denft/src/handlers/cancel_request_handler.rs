@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::CancelRequest;
+
+pub fn handler(ctx: Context<CancelRequest>) -> Result<()> {
+    crate::context::cancel_request::handler::cancel_request(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+use crate::context::CheckCredential;
+use crate::state::CredentialStatus;
+
+pub fn handler(ctx: Context<CheckCredential>) -> Result<CredentialStatus> {
+    crate::context::check_credential::handler::check_credential(ctx)
+}
\ No newline at end of file
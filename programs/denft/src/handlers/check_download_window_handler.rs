@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::CheckDownloadWindow;
+
+pub fn handler(ctx: Context<CheckDownloadWindow>) -> Result<Option<i64>> {
+    crate::context::check_download_window::handler::check_download_window(ctx)
+}
\ No newline at end of file
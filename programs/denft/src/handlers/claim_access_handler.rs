@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ClaimAccess;
+
+pub fn handler(ctx: Context<ClaimAccess>) -> Result<()> {
+    crate::context::claim_access::handler::claim_access(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ClaimEscrow;
+
+pub fn handler(ctx: Context<ClaimEscrow>) -> Result<()> {
+    crate::context::claim_escrow::handler::claim_escrow(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ConfirmEscrow;
+
+pub fn handler(ctx: Context<ConfirmEscrow>) -> Result<()> {
+    crate::context::confirm_escrow::handler::confirm_escrow(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::{context::CreateClaimableAccess, DownloadRateLimit};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateClaimableAccess>,
+    claim_key_hash: [u8; 32],
+    permissions: u8,
+    expires_at: Option<i64>,
+    max_downloads: Option<u32>,
+    claim_expires_at: i64,
+    not_before: Option<i64>,
+    rate_limit: Option<DownloadRateLimit>,
+) -> Result<()> {
+    crate::context::create_claimable_access::handler::create_claimable_access(
+        ctx,
+        claim_key_hash,
+        permissions,
+        expires_at,
+        max_downloads,
+        claim_expires_at,
+        not_before,
+        rate_limit,
+    )
+}
\ No newline at end of file
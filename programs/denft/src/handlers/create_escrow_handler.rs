@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::context::CreateEscrow;
+
+pub fn handler(
+    ctx: Context<CreateEscrow>,
+    freelancer: Pubkey,
+    file_hash: [u8; 32],
+    amount: u64,
+    deadline: i64,
+    dispute_window: i64,
+    arbiter: Pubkey,
+) -> Result<()> {
+    crate::context::create_escrow::handler::create_escrow(
+        ctx,
+        freelancer,
+        file_hash,
+        amount,
+        deadline,
+        dispute_window,
+        arbiter,
+    )
+}
\ No newline at end of file
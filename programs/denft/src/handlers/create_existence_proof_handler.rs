@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::CreateExistenceProof;
+
+pub fn handler(ctx: Context<CreateExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+    crate::context::create_existence_proof::handler::create_existence_proof(ctx, file_hash)
+}
\ No newline at end of file
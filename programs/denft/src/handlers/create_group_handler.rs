@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::CreateGroup;
+
+pub fn handler(ctx: Context<CreateGroup>, name: String) -> Result<()> {
+    crate::context::create_group::handler::create_group(ctx, name)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::{context::CreateProposal, ProposalAction};
+
+pub fn handler(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    crate::context::create_proposal::handler::create_proposal(ctx, action)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::DeliverEscrow;
+
+pub fn handler(ctx: Context<DeliverEscrow>) -> Result<()> {
+    crate::context::deliver_escrow::handler::deliver_escrow(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::DenyRequest;
+
+pub fn handler(ctx: Context<DenyRequest>) -> Result<()> {
+    crate::context::deny_request::handler::deny_request(ctx)
+}
\ No newline at end of file
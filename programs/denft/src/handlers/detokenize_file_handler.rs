@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::DetokenizeFile;
+
+pub fn handler(ctx: Context<DetokenizeFile>) -> Result<()> {
+    crate::context::detokenize_file::handler::detokenize_file(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::DisputeEscrow;
+
+pub fn handler(ctx: Context<DisputeEscrow>) -> Result<()> {
+    crate::context::dispute_escrow::handler::dispute_escrow(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::EnableCoOwnership;
+
+pub fn handler(ctx: Context<EnableCoOwnership>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    crate::context::enable_co_ownership::handler::enable_co_ownership(ctx, owners, threshold)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ExecuteDeleteFile;
+
+pub fn handler(ctx: Context<ExecuteDeleteFile>) -> Result<()> {
+    crate::context::execute_delete_file::handler::execute_delete_file(ctx)
+}
\ No newline at end of file
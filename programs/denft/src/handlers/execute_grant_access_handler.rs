@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ExecuteGrantAccess;
+
+pub fn handler(ctx: Context<ExecuteGrantAccess>) -> Result<()> {
+    crate::context::execute_grant_access::handler::execute_grant_access(ctx)
+}
\ No newline at end of file
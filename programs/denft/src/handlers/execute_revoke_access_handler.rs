@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ExecuteRevokeAccess;
+
+pub fn handler(ctx: Context<ExecuteRevokeAccess>) -> Result<()> {
+    crate::context::execute_revoke_access::handler::execute_revoke_access(ctx)
+}
\ No newline at end of file
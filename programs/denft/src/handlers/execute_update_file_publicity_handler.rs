@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ExecuteUpdateFilePublicity;
+
+pub fn handler(ctx: Context<ExecuteUpdateFilePublicity>) -> Result<()> {
+    crate::context::execute_update_file_publicity::handler::execute_update_file_publicity(ctx)
+}
\ No newline at end of file
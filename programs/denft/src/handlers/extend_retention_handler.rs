@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ExtendRetention;
+
+pub fn handler(ctx: Context<ExtendRetention>, retention_until: i64) -> Result<()> {
+    crate::context::extend_retention::handler::extend_retention(ctx, retention_until)
+}
\ No newline at end of file
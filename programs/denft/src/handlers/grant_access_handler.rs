@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::context::GrantAccess;
+use crate::{context::GrantAccess, DownloadRateLimit};
 
 pub fn handler(
     ctx: Context<GrantAccess>,
@@ -7,6 +7,8 @@ pub fn handler(
     permissions: u8,
     expires_at: Option<i64>,
     max_downloads: Option<u32>,
+    not_before: Option<i64>,
+    rate_limit: Option<DownloadRateLimit>,
 ) -> Result<()> {
     crate::context::grant_access::handler::grant_access(
         ctx,
@@ -14,5 +16,7 @@ pub fn handler(
         permissions,
         expires_at,
         max_downloads,
+        not_before,
+        rate_limit,
     )
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::InitializeAccessLog;
+
+pub fn handler(ctx: Context<InitializeAccessLog>, capacity: u16) -> Result<()> {
+    crate::context::initialize_access_log::handler::initialize_access_log(ctx, capacity)
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::context::IssueCredential;
+
+pub fn handler(
+    ctx: Context<IssueCredential>,
+    subject: Pubkey,
+    credential_hash: [u8; 32],
+    schema_id: String,
+    expires_at: Option<i64>,
+    status_list_id: u32,
+) -> Result<()> {
+    crate::context::issue_credential::handler::issue_credential(
+        ctx,
+        subject,
+        credential_hash,
+        schema_id,
+        expires_at,
+        status_list_id,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::MigrateAccessPermission;
+
+pub fn handler(ctx: Context<MigrateAccessPermission>) -> Result<()> {
+    crate::context::migrate_access_permission::handler::migrate_access_permission(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::MigrateFileRecord;
+
+pub fn handler(ctx: Context<MigrateFileRecord>) -> Result<()> {
+    crate::context::migrate_file_record::handler::migrate_file_record(ctx)
+}
\ No newline at end of file
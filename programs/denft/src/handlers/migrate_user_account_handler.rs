@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::MigrateUserAccount;
+
+pub fn handler(ctx: Context<MigrateUserAccount>) -> Result<()> {
+    crate::context::migrate_user_account::handler::migrate_user_account(ctx)
+}
\ No newline at end of file
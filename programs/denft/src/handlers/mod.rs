@@ -4,6 +4,61 @@ pub mod verify_file_handler;
 pub mod update_file_publicity_handler;
 pub mod delete_file_handler;
 pub mod record_file_access_handler;
+pub mod check_download_window_handler;
 pub mod grant_access_handler;
 pub mod revoke_access_handler;
-
+pub mod enable_co_ownership_handler;
+pub mod create_proposal_handler;
+pub mod approve_proposal_handler;
+pub mod execute_delete_file_handler;
+pub mod execute_grant_access_handler;
+pub mod execute_revoke_access_handler;
+pub mod execute_update_file_publicity_handler;
+pub mod request_access_handler;
+pub mod approve_request_handler;
+pub mod deny_request_handler;
+pub mod cancel_request_handler;
+pub mod sweep_expired_request_handler;
+pub mod create_group_handler;
+pub mod add_group_member_handler;
+pub mod remove_group_member_handler;
+pub mod create_claimable_access_handler;
+pub mod claim_access_handler;
+pub mod cancel_claimable_access_handler;
+pub mod update_file_release_handler;
+pub mod sweep_expired_grant_handler;
+pub mod initialize_access_log_handler;
+pub mod resize_access_log_handler;
+pub mod restore_file_handler;
+pub mod purge_file_handler;
+pub mod extend_retention_handler;
+pub mod set_legal_hold_handler;
+pub mod set_hold_authority_handler;
+pub mod create_existence_proof_handler;
+pub mod verify_existence_proof_handler;
+pub mod attest_file_handler;
+pub mod revoke_attestation_handler;
+pub mod record_signature_handler;
+pub mod issue_credential_handler;
+pub mod revoke_credential_handler;
+pub mod check_credential_handler;
+pub mod tokenize_file_handler;
+pub mod detokenize_file_handler;
+pub mod set_token_gate_handler;
+pub mod remove_token_gate_handler;
+pub mod update_file_metadata_handler;
+pub mod migrate_user_account_handler;
+pub mod migrate_file_record_handler;
+pub mod migrate_access_permission_handler;
+pub mod reactivate_file_handler;
+pub mod set_protocol_fee_handler;
+pub mod set_access_price_handler;
+pub mod remove_access_price_handler;
+pub mod purchase_access_handler;
+pub mod create_escrow_handler;
+pub mod deliver_escrow_handler;
+pub mod confirm_escrow_handler;
+pub mod claim_escrow_handler;
+pub mod refund_escrow_handler;
+pub mod dispute_escrow_handler;
+pub mod resolve_escrow_handler;
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::PurchaseAccess;
+
+pub fn handler(ctx: Context<PurchaseAccess>, max_price: u64) -> Result<()> {
+    crate::context::purchase_access::handler::purchase_access(ctx, max_price)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::PurgeFile;
+
+pub fn handler(ctx: Context<PurgeFile>) -> Result<()> {
+    crate::context::purge_file::handler::purge_file(ctx)
+}
\ No newline at end of file
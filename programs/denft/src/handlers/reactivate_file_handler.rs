@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::context::ReactivateFile;
+
+pub fn handler(
+    ctx: Context<ReactivateFile>,
+    ipfs_hash: String,
+    encrypted_metadata: String,
+    content_type: String,
+    description: String,
+) -> Result<()> {
+    crate::context::reactivate_file::handler::reactivate_file(
+        ctx,
+        ipfs_hash,
+        encrypted_metadata,
+        content_type,
+        description,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RecordSignature;
+
+pub fn handler(ctx: Context<RecordSignature>, signer: Pubkey) -> Result<()> {
+    crate::context::record_signature::handler::record_signature(ctx, signer)
+}
\ No newline at end of file
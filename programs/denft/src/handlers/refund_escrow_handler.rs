@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RefundEscrow;
+
+pub fn handler(ctx: Context<RefundEscrow>) -> Result<()> {
+    crate::context::refund_escrow::handler::refund_escrow(ctx)
+}
\ No newline at end of file
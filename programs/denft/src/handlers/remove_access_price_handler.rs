@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RemoveAccessPrice;
+
+pub fn handler(ctx: Context<RemoveAccessPrice>) -> Result<()> {
+    crate::context::remove_access_price::handler::remove_access_price(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RemoveGroupMember;
+
+pub fn handler(ctx: Context<RemoveGroupMember>) -> Result<()> {
+    crate::context::remove_group_member::handler::remove_group_member(ctx)
+}
\ No newline at end of file
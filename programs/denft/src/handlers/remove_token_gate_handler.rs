@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RemoveTokenGate;
+
+pub fn handler(ctx: Context<RemoveTokenGate>) -> Result<()> {
+    crate::context::remove_token_gate::handler::remove_token_gate(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use crate::context::RequestAccess;
+
+pub fn handler(
+    ctx: Context<RequestAccess>,
+    permissions: u8,
+    message_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    crate::context::request_access::handler::request_access(
+        ctx,
+        permissions,
+        message_hash,
+        expires_at,
+    )
+}
\ No newline at end of file
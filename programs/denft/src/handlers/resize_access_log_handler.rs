@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ResizeAccessLog;
+
+pub fn handler(ctx: Context<ResizeAccessLog>, capacity: u16) -> Result<()> {
+    crate::context::resize_access_log::handler::resize_access_log(ctx, capacity)
+}
\ No newline at end of file
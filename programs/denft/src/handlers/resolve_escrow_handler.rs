@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::ResolveEscrow;
+
+pub fn handler(ctx: Context<ResolveEscrow>, release_to_freelancer: bool) -> Result<()> {
+    crate::context::resolve_escrow::handler::resolve_escrow(ctx, release_to_freelancer)
+}
\ No newline at end of file
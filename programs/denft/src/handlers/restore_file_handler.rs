@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RestoreFile;
+
+pub fn handler(ctx: Context<RestoreFile>) -> Result<()> {
+    crate::context::restore_file::handler::restore_file(ctx)
+}
\ No newline at end of file
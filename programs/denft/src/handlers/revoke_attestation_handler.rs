@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RevokeAttestation;
+
+pub fn handler(ctx: Context<RevokeAttestation>) -> Result<()> {
+    crate::context::revoke_attestation::handler::revoke_attestation(ctx)
+}
\ No newline at end of file
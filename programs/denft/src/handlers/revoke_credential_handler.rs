@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::RevokeCredential;
+
+pub fn handler(ctx: Context<RevokeCredential>) -> Result<()> {
+    crate::context::revoke_credential::handler::revoke_credential(ctx)
+}
\ No newline at end of file
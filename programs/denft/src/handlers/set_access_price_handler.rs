@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::context::SetAccessPrice;
+
+pub fn handler(
+    ctx: Context<SetAccessPrice>,
+    price: u64,
+    permissions: u8,
+    access_duration: Option<i64>,
+    max_downloads: Option<u32>,
+) -> Result<()> {
+    crate::context::set_access_price::handler::set_access_price(
+        ctx,
+        price,
+        permissions,
+        access_duration,
+        max_downloads,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::SetHoldAuthority;
+
+pub fn handler(ctx: Context<SetHoldAuthority>, hold_authority: Pubkey) -> Result<()> {
+    crate::context::set_hold_authority::handler::set_hold_authority(ctx, hold_authority)
+}
\ No newline at end of file
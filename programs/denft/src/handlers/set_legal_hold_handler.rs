@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::SetLegalHold;
+
+pub fn handler(ctx: Context<SetLegalHold>, legal_hold: bool) -> Result<()> {
+    crate::context::set_legal_hold::handler::set_legal_hold(ctx, legal_hold)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::SetProtocolFee;
+
+pub fn handler(ctx: Context<SetProtocolFee>, treasury: Pubkey, fee_bps: u16) -> Result<()> {
+    crate::context::set_protocol_fee::handler::set_protocol_fee(ctx, treasury, fee_bps)
+}
\ No newline at end of file
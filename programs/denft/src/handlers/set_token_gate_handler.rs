@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::{context::SetTokenGate, TokenGateKind};
+
+pub fn handler(ctx: Context<SetTokenGate>, kind: TokenGateKind, permissions: u8) -> Result<()> {
+    crate::context::set_token_gate::handler::set_token_gate(ctx, kind, permissions)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::SweepExpiredGrant;
+
+pub fn handler(ctx: Context<SweepExpiredGrant>) -> Result<()> {
+    crate::context::sweep_expired_grant::handler::sweep_expired_grant(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::SweepExpiredRequest;
+
+pub fn handler(ctx: Context<SweepExpiredRequest>) -> Result<()> {
+    crate::context::sweep_expired_request::handler::sweep_expired_request(ctx)
+}
\ No newline at end of file
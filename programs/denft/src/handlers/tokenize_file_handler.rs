@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::TokenizeFile;
+
+pub fn handler(ctx: Context<TokenizeFile>) -> Result<()> {
+    crate::context::tokenize_file::handler::tokenize_file(ctx)
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use crate::context::UpdateFileMetadata;
+
+pub fn handler(
+    ctx: Context<UpdateFileMetadata>,
+    description: String,
+    content_type: String,
+    encrypted_metadata: String,
+) -> Result<()> {
+    crate::context::update_file_metadata::handler::update_file_metadata(
+        ctx,
+        description,
+        content_type,
+        encrypted_metadata,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::UpdateFileRelease;
+
+pub fn handler(ctx: Context<UpdateFileRelease>, not_before: Option<i64>) -> Result<()> {
+    crate::context::update_file_release::handler::update_file_release(ctx, not_before)
+}
\ No newline at end of file
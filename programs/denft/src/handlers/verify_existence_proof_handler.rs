@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+use crate::context::VerifyExistenceProof;
+
+pub fn handler(ctx: Context<VerifyExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+    crate::context::verify_existence_proof::handler::verify_existence_proof(ctx, file_hash)
+}
\ No newline at end of file
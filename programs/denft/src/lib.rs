@@ -8,6 +8,7 @@ pub mod errors;
 pub mod handlers;
 pub mod events;
 pub mod generate_verification_id;
+pub mod verify_ed25519_instruction;
 
 pub use context::*;
 pub use state::*;
@@ -15,6 +16,7 @@ pub use errors::*;
 pub use handlers::*;
 pub use events::*;
 pub use generate_verification_id::*;
+pub use verify_ed25519_instruction::*;
 
 declare_id!("9fUNv3nRE8a5CjLUEzzJmA9o5Sw2wstnfoaseobXHqkW");
 
@@ -48,7 +50,7 @@ pub mod denft {
         )
     }
 
-    /// Grant access to a file for another user
+    /// Grant access to a file for another user or a group address
     /// Creates AccessPermission record with specific permissions and expiration
     pub fn grant_access(
         ctx: Context<GrantAccess>,
@@ -56,8 +58,18 @@ pub mod denft {
         permissions: u8,
         expires_at: Option<i64>,
         max_downloads: Option<u32>,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
     ) -> Result<()> {
-        handlers::grant_access_handler::handler(ctx, accessor, permissions, expires_at, max_downloads)
+        handlers::grant_access_handler::handler(
+            ctx,
+            accessor,
+            permissions,
+            expires_at,
+            max_downloads,
+            not_before,
+            rate_limit,
+        )
     }
 
 
@@ -74,7 +86,7 @@ pub mod denft {
     }
 
     /// Record file access for audit trail
-    /// Updates download statistics and logs access
+    /// Requires the permission bit for the access type unless the signer owns the file
     pub fn record_file_access(
         ctx: Context<RecordFileAccess>,
         access_type: AccessType,
@@ -82,6 +94,12 @@ pub mod denft {
         handlers::record_file_access_handler::handler(ctx, access_type)
     }
 
+    /// Check when a rate-limited grant can download again
+    /// Returns the end of the exhausted window via return data, or None if a download would pass
+    pub fn check_download_window(ctx: Context<CheckDownloadWindow>) -> Result<Option<i64>> {
+        handlers::check_download_window_handler::handler(ctx)
+    }
+
     /// Update file publicity for verification
     /// Allows/disallows public verification without account
     pub fn update_file_publicity(
@@ -93,9 +111,441 @@ pub mod denft {
 
 
     // Delete a file (mark as inactive)
-    /// Updates user storage statistics and marks file as inactive
+    /// Updates user storage statistics and moves the file to the trash
     pub fn delete_file(ctx: Context<DeleteFile>) -> Result<()> {
         handlers::delete_file_handler::handler(ctx)
     }
-}
 
+    /// Turn a file into a co-owned file governed by an M-of-N owner set
+    /// After this, privileged operations require approved proposals
+    pub fn enable_co_ownership(
+        ctx: Context<EnableCoOwnership>,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        handlers::enable_co_ownership_handler::handler(ctx, owners, threshold)
+    }
+
+    /// Propose a privileged operation on a co-owned file
+    /// The proposer's approval is recorded automatically
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        handlers::create_proposal_handler::handler(ctx, action)
+    }
+
+    /// Approve a pending proposal as one of the file's co-owners
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        handlers::approve_proposal_handler::handler(ctx)
+    }
+
+    /// Execute an approved DeleteFile proposal
+    pub fn execute_delete_file(ctx: Context<ExecuteDeleteFile>) -> Result<()> {
+        handlers::execute_delete_file_handler::handler(ctx)
+    }
+
+    /// Execute an approved GrantAccess proposal
+    pub fn execute_grant_access(ctx: Context<ExecuteGrantAccess>) -> Result<()> {
+        handlers::execute_grant_access_handler::handler(ctx)
+    }
+
+    /// Execute an approved RevokeAccess proposal
+    pub fn execute_revoke_access(ctx: Context<ExecuteRevokeAccess>) -> Result<()> {
+        handlers::execute_revoke_access_handler::handler(ctx)
+    }
+
+    /// Execute an approved UpdateFilePublicity proposal
+    pub fn execute_update_file_publicity(ctx: Context<ExecuteUpdateFilePublicity>) -> Result<()> {
+        handlers::execute_update_file_publicity_handler::handler(ctx)
+    }
+
+    /// Request access to a file as a prospective accessor
+    /// Creates an AccessRequest that the owner can approve or deny
+    pub fn request_access(
+        ctx: Context<RequestAccess>,
+        permissions: u8,
+        message_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        handlers::request_access_handler::handler(ctx, permissions, message_hash, expires_at)
+    }
+
+    /// Approve a pending access request
+    /// Creates the AccessPermission and refunds the request rent to the requester
+    pub fn approve_request(
+        ctx: Context<ApproveRequest>,
+        expires_at: Option<i64>,
+        max_downloads: Option<u32>,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
+    ) -> Result<()> {
+        handlers::approve_request_handler::handler(
+            ctx,
+            expires_at,
+            max_downloads,
+            not_before,
+            rate_limit,
+        )
+    }
+
+    /// Deny a pending access request
+    /// Closes the AccessRequest and refunds its rent to the requester
+    pub fn deny_request(ctx: Context<DenyRequest>) -> Result<()> {
+        handlers::deny_request_handler::handler(ctx)
+    }
+
+    /// Withdraw a pending access request
+    /// Called by the requester; refunds the request rent to them
+    pub fn cancel_request(ctx: Context<CancelRequest>) -> Result<()> {
+        handlers::cancel_request_handler::handler(ctx)
+    }
+
+    /// Close an access request that expired without an answer
+    /// Anyone may call it; the rent goes back to the requester
+    pub fn sweep_expired_request(ctx: Context<SweepExpiredRequest>) -> Result<()> {
+        handlers::sweep_expired_request_handler::handler(ctx)
+    }
+
+    /// Create a sharing group managed by the signer
+    /// Files granted to the group address are accessible to every member
+    pub fn create_group(ctx: Context<CreateGroup>, name: String) -> Result<()> {
+        handlers::create_group_handler::handler(ctx, name)
+    }
+
+    /// Add a member to a group
+    /// Creates a GroupMembership that proves membership in record_file_access
+    pub fn add_group_member(ctx: Context<AddGroupMember>) -> Result<()> {
+        handlers::add_group_member_handler::handler(ctx)
+    }
+
+    /// Remove a member from a group
+    /// Closes the GroupMembership and refunds its rent to the admin
+    pub fn remove_group_member(ctx: Context<RemoveGroupMember>) -> Result<()> {
+        handlers::remove_group_member_handler::handler(ctx)
+    }
+
+    /// Create a share link that anyone holding the one-time claim key can redeem
+    /// Stores only the hash of the claim public key
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_claimable_access(
+        ctx: Context<CreateClaimableAccess>,
+        claim_key_hash: [u8; 32],
+        permissions: u8,
+        expires_at: Option<i64>,
+        max_downloads: Option<u32>,
+        claim_expires_at: i64,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
+    ) -> Result<()> {
+        handlers::create_claimable_access_handler::handler(
+            ctx,
+            claim_key_hash,
+            permissions,
+            expires_at,
+            max_downloads,
+            claim_expires_at,
+            not_before,
+            rate_limit,
+        )
+    }
+
+    /// Redeem a share link by co-signing with its one-time claim key
+    /// Converts the pending grant into an AccessPermission bound to the claimer
+    pub fn claim_access(ctx: Context<ClaimAccess>) -> Result<()> {
+        handlers::claim_access_handler::handler(ctx)
+    }
+
+    /// Cancel an unclaimed or expired share link and recover its rent
+    /// Still works once the shared file has been purged
+    pub fn cancel_claimable_access(ctx: Context<CancelClaimableAccess>) -> Result<()> {
+        handlers::cancel_claimable_access_handler::handler(ctx)
+    }
+
+    /// Schedule or clear a time-locked release for a file
+    /// Access and public verification are refused before not_before
+    pub fn update_file_release(ctx: Context<UpdateFileRelease>, not_before: Option<i64>) -> Result<()> {
+        handlers::update_file_release_handler::handler(ctx, not_before)
+    }
+
+    /// Close an expired, revoked or orphaned AccessPermission
+    /// Permissionless; the rent goes back to the original payer
+    pub fn sweep_expired_grant(ctx: Context<SweepExpiredGrant>) -> Result<()> {
+        handlers::sweep_expired_grant_handler::handler(ctx)
+    }
+
+    /// Create an on-chain access log for a file
+    /// Keeps the most recent `capacity` accesses and verifications
+    pub fn initialize_access_log(ctx: Context<InitializeAccessLog>, capacity: u16) -> Result<()> {
+        handlers::initialize_access_log_handler::handler(ctx, capacity)
+    }
+
+    /// Change the capacity of a file's access log
+    /// Shrinking keeps the most recent entries; rent is charged or refunded
+    pub fn resize_access_log(ctx: Context<ResizeAccessLog>, capacity: u16) -> Result<()> {
+        handlers::resize_access_log_handler::handler(ctx, capacity)
+    }
+
+    /// Restore a deleted file from the trash
+    /// Re-adds its storage to the user's quota if it still fits
+    pub fn restore_file(ctx: Context<RestoreFile>) -> Result<()> {
+        handlers::restore_file_handler::handler(ctx)
+    }
+
+    /// Permanently close a deleted file after its trash retention period
+    /// Permissionless; closes the access log, leaves a tombstone and returns the rest of the rent
+    pub fn purge_file(ctx: Context<PurgeFile>) -> Result<()> {
+        handlers::purge_file_handler::handler(ctx)
+    }
+
+    /// Lock a file against deletion, publicity downgrade and transfer until a date
+    /// Retention can only be extended, never shortened
+    pub fn extend_retention(ctx: Context<ExtendRetention>, retention_until: i64) -> Result<()> {
+        handlers::extend_retention_handler::handler(ctx, retention_until)
+    }
+
+    /// Place or release a legal hold on a file; signed by the hold authority
+    /// While held, the file cannot be deleted, made private or transferred
+    pub fn set_legal_hold(ctx: Context<SetLegalHold>, legal_hold: bool) -> Result<()> {
+        handlers::set_legal_hold_handler::handler(ctx, legal_hold)
+    }
+
+    /// Appoint the key that places and releases legal holds
+    /// Restricted to the program's upgrade authority
+    pub fn set_hold_authority(ctx: Context<SetHoldAuthority>, hold_authority: Pubkey) -> Result<()> {
+        handlers::set_hold_authority_handler::handler(ctx, hold_authority)
+    }
+
+    /// Timestamp a document hash without uploading it anywhere
+    /// Does not count against storage quota
+    pub fn create_existence_proof(ctx: Context<CreateExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+        handlers::create_existence_proof_handler::handler(ctx, file_hash)
+    }
+
+    /// Verify that a hash was anchored by an existence proof
+    /// Emits the slot and timestamp it was recorded at
+    pub fn verify_existence_proof(ctx: Context<VerifyExistenceProof>, file_hash: [u8; 32]) -> Result<()> {
+        handlers::verify_existence_proof_handler::handler(ctx, file_hash)
+    }
+
+    /// Co-sign a file hash as a third-party attester
+    /// Creates an Attestation keyed by file and attester
+    pub fn attest_file(
+        ctx: Context<AttestFile>,
+        file_hash: [u8; 32],
+        claim_type: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        handlers::attest_file_handler::handler(ctx, file_hash, claim_type, expires_at)
+    }
+
+    /// Revoke an attestation previously made by the signer
+    /// The record is kept so the revocation stays auditable
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        handlers::revoke_attestation_handler::handler(ctx)
+    }
+
+    /// Record an off-chain ed25519 signature over a file hash
+    /// Must follow an ed25519 program instruction verifying the signature
+    pub fn record_signature(ctx: Context<RecordSignature>, signer: Pubkey) -> Result<()> {
+        handlers::record_signature_handler::handler(ctx, signer)
+    }
+
+    /// Issue a credential to a subject wallet
+    /// Creates a Credential keyed by issuer and credential hash, indexed in a status list
+    pub fn issue_credential(
+        ctx: Context<IssueCredential>,
+        subject: Pubkey,
+        credential_hash: [u8; 32],
+        schema_id: String,
+        expires_at: Option<i64>,
+        status_list_id: u32,
+    ) -> Result<()> {
+        handlers::issue_credential_handler::handler(ctx,
+            subject,
+            credential_hash,
+            schema_id,
+            expires_at,
+            status_list_id,
+        )
+    }
+
+    /// Revoke a credential issued by the signer
+    /// Revocation is permanent and sets the credential's bit in its status list
+    pub fn revoke_credential(ctx: Context<RevokeCredential>) -> Result<()> {
+        handlers::revoke_credential_handler::handler(ctx)
+    }
+
+    /// Check whether a credential is valid, expired or revoked
+    /// The status is returned via return data
+    pub fn check_credential(ctx: Context<CheckCredential>) -> Result<CredentialStatus> {
+        handlers::check_credential_handler::handler(ctx)
+    }
+
+    /// Mint a supply-1 token representing ownership of a file
+    /// The token holder acts as owner for all privileged instructions
+    pub fn tokenize_file(ctx: Context<TokenizeFile>) -> Result<()> {
+        handlers::tokenize_file_handler::handler(ctx)
+    }
+
+    /// Burn a file's ownership token
+    /// Ownership is pinned back to the wallet that held it
+    pub fn detokenize_file(ctx: Context<DetokenizeFile>) -> Result<()> {
+        handlers::detokenize_file_handler::handler(ctx)
+    }
+
+    /// Admit holders of an SPL mint or NFT collection to a file
+    /// Holders get the gate's permissions without an AccessPermission each
+    pub fn set_token_gate(
+        ctx: Context<SetTokenGate>,
+        kind: TokenGateKind,
+        permissions: u8,
+    ) -> Result<()> {
+        handlers::set_token_gate_handler::handler(ctx, kind, permissions)
+    }
+
+    /// Remove a file's token gate
+    /// Rent is returned to the owner who attached the gate
+    pub fn remove_token_gate(ctx: Context<RemoveTokenGate>) -> Result<()> {
+        handlers::remove_token_gate_handler::handler(ctx)
+    }
+
+    /// Edit a file's description, content type and encrypted metadata
+    /// The record is resized to fit, charging or refunding rent
+    pub fn update_file_metadata(
+        ctx: Context<UpdateFileMetadata>,
+        description: String,
+        content_type: String,
+        encrypted_metadata: String,
+    ) -> Result<()> {
+        handlers::update_file_metadata_handler::handler(ctx, description, content_type, encrypted_metadata)
+    }
+
+    /// Upgrade a UserAccount to the current layout version
+    /// Permissionless; the payer covers any extra rent
+    pub fn migrate_user_account(ctx: Context<MigrateUserAccount>) -> Result<()> {
+        handlers::migrate_user_account_handler::handler(ctx)
+    }
+
+    /// Upgrade a FileRecord to the current layout version
+    /// Permissionless; the payer covers any extra rent
+    pub fn migrate_file_record(ctx: Context<MigrateFileRecord>) -> Result<()> {
+        handlers::migrate_file_record_handler::handler(ctx)
+    }
+
+    /// Upgrade an AccessPermission to the current layout version
+    /// Permissionless; the payer covers any extra rent
+    pub fn migrate_access_permission(ctx: Context<MigrateAccessPermission>) -> Result<()> {
+        handlers::migrate_access_permission_handler::handler(ctx)
+    }
+
+    /// Re-upload the content of a deleted file into its existing record
+    /// Resets counters, retires earlier grants, re-charges the quota and issues a fresh verification id
+    pub fn reactivate_file(
+        ctx: Context<ReactivateFile>,
+        ipfs_hash: String,
+        encrypted_metadata: String,
+        content_type: String,
+        description: String,
+    ) -> Result<()> {
+        handlers::reactivate_file_handler::handler(ctx,
+            ipfs_hash,
+            encrypted_metadata,
+            content_type,
+            description,
+        )
+    }
+
+    /// Set the protocol's cut of access sales and where it is paid
+    /// Restricted to the program's upgrade authority
+    pub fn set_protocol_fee(
+        ctx: Context<SetProtocolFee>,
+        treasury: Pubkey,
+        fee_bps: u16,
+    ) -> Result<()> {
+        handlers::set_protocol_fee_handler::handler(ctx, treasury, fee_bps)
+    }
+
+    /// List a file's access for sale, or change an existing listing
+    /// Buyers receive the listing's permissions, duration and download cap
+    pub fn set_access_price(
+        ctx: Context<SetAccessPrice>,
+        price: u64,
+        permissions: u8,
+        access_duration: Option<i64>,
+        max_downloads: Option<u32>,
+    ) -> Result<()> {
+        handlers::set_access_price_handler::handler(ctx,
+            price,
+            permissions,
+            access_duration,
+            max_downloads,
+        )
+    }
+
+    /// Take a file off sale and reclaim the listing's rent
+    /// Permissions already sold are unaffected
+    pub fn remove_access_price(ctx: Context<RemoveAccessPrice>) -> Result<()> {
+        handlers::remove_access_price_handler::handler(ctx)
+    }
+
+    /// Buy access to a listed file
+    /// Pays the owner minus the protocol fee and grants the listed permissions
+    pub fn purchase_access(ctx: Context<PurchaseAccess>, max_price: u64) -> Result<()> {
+        handlers::purchase_access_handler::handler(ctx, max_price)
+    }
+
+    /// Escrow payment for a file a freelancer has yet to deliver
+    /// Refundable if nothing is delivered by the deadline; disputes go to the arbiter
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        freelancer: Pubkey,
+        file_hash: [u8; 32],
+        amount: u64,
+        deadline: i64,
+        dispute_window: i64,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        handlers::create_escrow_handler::handler(ctx,
+            freelancer,
+            file_hash,
+            amount,
+            deadline,
+            dispute_window,
+            arbiter,
+        )
+    }
+
+    /// Record delivery of the escrowed file once it is uploaded and shared
+    /// Starts the client's dispute window
+    pub fn deliver_escrow(ctx: Context<DeliverEscrow>) -> Result<()> {
+        handlers::deliver_escrow_handler::handler(ctx)
+    }
+
+    /// Release escrowed funds to the freelancer
+    /// Called by the client once satisfied, unless the delivery is disputed
+    pub fn confirm_escrow(ctx: Context<ConfirmEscrow>) -> Result<()> {
+        handlers::confirm_escrow_handler::handler(ctx)
+    }
+
+    /// Release escrowed funds after an undisputed delivery
+    /// Called by the freelancer once the dispute window has passed
+    pub fn claim_escrow(ctx: Context<ClaimEscrow>) -> Result<()> {
+        handlers::claim_escrow_handler::handler(ctx)
+    }
+
+    /// Return escrowed funds to the client
+    /// Allowed when nothing was delivered by the deadline, or a dispute outlived its resolution period
+    pub fn refund_escrow(ctx: Context<RefundEscrow>) -> Result<()> {
+        handlers::refund_escrow_handler::handler(ctx)
+    }
+
+    /// Reject a delivery inside the dispute window
+    /// Freezes the escrowed funds until the arbiter settles them or the resolution period lapses
+    pub fn dispute_escrow(ctx: Context<DisputeEscrow>) -> Result<()> {
+        handlers::dispute_escrow_handler::handler(ctx)
+    }
+
+    /// Settle a disputed escrow
+    /// Called by the arbiter; pays the freelancer or refunds the client
+    pub fn resolve_escrow(ctx: Context<ResolveEscrow>, release_to_freelancer: bool) -> Result<()> {
+        handlers::resolve_escrow_handler::handler(ctx, release_to_freelancer)
+    }
+}
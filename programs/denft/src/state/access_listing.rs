@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::GrantTerms;
+
+#[account]
+pub struct AccessListing {
+    pub file_record: Pubkey,          // File whose access is for sale
+    pub price: u64,                   // Lamports the buyer pays per purchase, protocol fee included
+    pub permissions: u8,              // Permission bitflags of the purchased AccessPermission
+    pub access_duration: Option<i64>, // Seconds from purchase until the permission expires (optional)
+    pub max_downloads: Option<u32>,   // Download cap of the purchased AccessPermission (optional)
+    pub created_by: Pubkey,           // Owner who listed the file, also the rent payer
+    pub updated_at: i64,
+    pub sales: u64,                   // Number of purchases so far
+    pub reserved: [u8; 16],           // Reserved space for future features
+}
+
+impl AccessListing {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        8 +   // price
+        1 +   // permissions
+        9 +   // access_duration (Option<i64>)
+        5 +   // max_downloads (Option<u32>)
+        32 +  // created_by
+        8 +   // updated_at
+        8 +   // sales
+        16;   // reserved space
+
+    /// Terms of the permission a purchase made at `timestamp` receives
+    pub fn terms(&self, timestamp: i64) -> GrantTerms {
+        GrantTerms {
+            permissions: self.permissions,
+            not_before: None,
+            expires_at: self.access_duration.map(|duration| timestamp + duration),
+            max_downloads: self.max_downloads,
+            rate_limit: None,
+        }
+    }
+}
\ No newline at end of file
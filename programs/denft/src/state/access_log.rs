@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::AccessType;
+
+/// Kinds of access written to an AccessLog
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoggedAccessType {
+    Read,
+    Download,
+    Share,
+    Verify,
+}
+
+impl From<AccessType> for LoggedAccessType {
+    fn from(access_type: AccessType) -> Self {
+        match access_type {
+            AccessType::Read => LoggedAccessType::Read,
+            AccessType::Download => LoggedAccessType::Download,
+            AccessType::Share => LoggedAccessType::Share,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccessLogEntry {
+    pub accessor: Pubkey,
+    pub access_type: LoggedAccessType,
+    pub timestamp: i64,
+    pub slot: u64,
+}
+
+impl AccessLogEntry {
+    pub const LEN: usize = 32 + 1 + 8 + 8;
+}
+
+#[account]
+pub struct AccessLog {
+    pub file_record: Pubkey,          // The file record whose accesses are logged
+    pub capacity: u16,                // Maximum number of retained entries
+    pub head: u16,                    // Index of the next entry to write once the buffer is full
+    pub total_entries: u64,           // Number of entries ever written, including overwritten ones
+    pub entries: Vec<AccessLogEntry>, // Ring buffer of the most recent entries
+}
+
+impl AccessLog {
+    pub const BASE_LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        2 +   // capacity
+        2 +   // head
+        8 +   // total_entries
+        4;    // entries vec prefix
+
+    pub fn space_required(capacity: u16) -> usize {
+        AccessLog::BASE_LEN + AccessLogEntry::LEN * capacity as usize
+    }
+
+    pub fn record(&mut self, entry: AccessLogEntry) {
+        if self.entries.len() < self.capacity as usize {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.head as usize] = entry;
+        }
+        self.head = ((self.head as usize + 1) % self.capacity as usize) as u16;
+        self.total_entries += 1;
+    }
+
+    /// Entries from oldest to newest
+    pub fn ordered_entries(&self) -> Vec<AccessLogEntry> {
+        if self.entries.len() < self.capacity as usize {
+            return self.entries.clone();
+        }
+        let (newest, oldest) = self.entries.split_at(self.head as usize);
+        oldest.iter().chain(newest.iter()).copied().collect()
+    }
+
+    /// Change the capacity, keeping the most recent entries that still fit
+    pub fn resize(&mut self, capacity: u16) {
+        let ordered = self.ordered_entries();
+        let keep = ordered.len().min(capacity as usize);
+        self.entries = ordered[ordered.len() - keep..].to_vec();
+        self.capacity = capacity;
+        self.head = (self.entries.len() % capacity as usize) as u16;
+    }
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::clock::Clock;
 
+use crate::{FileRecord, Versioned, CURRENT_ACCOUNT_VERSION};
+
 // Permission bitflags
 pub const PERMISSION_READ: u8 = 1;
 pub const PERMISSION_DOWNLOAD: u8 = 2;
@@ -22,6 +24,69 @@ impl AccessType {
             _ => None,
         }
     }
+
+    pub fn permission_flag(&self) -> u8 {
+        match self {
+            AccessType::Read => PERMISSION_READ,
+            AccessType::Download => PERMISSION_DOWNLOAD,
+            AccessType::Share => PERMISSION_SHARE,
+        }
+    }
+
+    pub fn missing_permission_error(&self) -> crate::DenftError {
+        match self {
+            AccessType::Read => crate::DenftError::MissingReadPermission,
+            AccessType::Download => crate::DenftError::MissingDownloadPermission,
+            AccessType::Share => crate::DenftError::MissingSharePermission,
+        }
+    }
+}
+
+/// At most `max_downloads` downloads in any `window_seconds` period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DownloadRateLimit {
+    pub max_downloads: u32,
+    pub window_seconds: i64,
+}
+
+impl DownloadRateLimit {
+    pub const LEN: usize = 4 + 8;
+
+    pub fn is_valid(&self) -> bool {
+        self.max_downloads > 0 && self.window_seconds > 0
+    }
+}
+
+/// Terms shared by every path that creates an AccessPermission
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GrantTerms {
+    pub permissions: u8,
+    pub not_before: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub max_downloads: Option<u32>,
+    pub rate_limit: Option<DownloadRateLimit>,
+}
+
+impl GrantTerms {
+    pub fn validate(&self, timestamp: i64) -> Result<()> {
+        require!(
+            AccessPermission::is_valid_permission(self.permissions),
+            crate::DenftError::InvalidPermissions
+        );
+
+        if let Some(expiry) = self.expires_at {
+            require!(expiry > timestamp, crate::DenftError::InvalidExpirationTime);
+            if let Some(start) = self.not_before {
+                require!(start < expiry, crate::DenftError::InvalidAccessWindow);
+            }
+        }
+
+        if let Some(rate_limit) = self.rate_limit {
+            require!(rate_limit.is_valid(), crate::DenftError::InvalidRateLimit);
+        }
+
+        Ok(())
+    }
 }
 
 #[account]
@@ -36,7 +101,13 @@ pub struct AccessPermission {
     pub granted_by: Pubkey,         // Who granted the access
     pub is_active: bool,            // Access status
     pub revoked_at: Option<i64>,   // When access was revoked (optional)
-    pub reserved: [u8; 16],         // Reserved space for future features
+    pub not_before: Option<i64>,   // Access is not usable before this time (optional)
+    pub rate_limit: Option<DownloadRateLimit>, // Per-window download cap (optional)
+    pub window_downloads: u32,     // Downloads used in the current rate window
+    pub window_start: i64,         // Start of the current rate window
+    pub payer: Pubkey,             // Paid the account rent, refunded when the grant is swept
+    pub version: u8,               // Layout version, 0 for grants created before versioning
+    pub reserved: [u8; 6],          // Reserved space for future features
 }
 
 impl AccessPermission {
@@ -51,12 +122,56 @@ impl AccessPermission {
         32 +  // granted_by
         1 +   // is_active
         9 +   // revoked_at (Option<i64>)
-        16;   // reserved space
+        9 +   // not_before (Option<i64>)
+        1 + DownloadRateLimit::LEN + // rate_limit (Option<DownloadRateLimit>)
+        4 +   // window_downloads
+        8 +   // window_start
+        32 +  // payer
+        1 +   // version
+        6;    // reserved space
+
+    /// Upgrade the layout one version at a time up to the current one
+    pub fn migrate(&mut self) {
+        // v0 -> v1: the version byte was carved out of `reserved`. Grants older than the `payer`
+        // field keep the default key, which sweep_expired_grant refuses rather than guess who paid
+        if self.version == 0 {
+            self.version = 1;
+        }
+        debug_assert_eq!(self.version, CURRENT_ACCOUNT_VERSION);
+    }
 
     pub fn is_valid(&self) -> bool {
         let current_timestamp = Clock::get().unwrap().unix_timestamp;
         self.is_active &&
-        self.expires_at.map_or(true, |exp| current_timestamp <= exp)
+        self.has_started(current_timestamp) &&
+        !self.is_expired(current_timestamp)
+    }
+
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        match self.expires_at {
+            Some(exp) => timestamp > exp,
+            None => false,
+        }
+    }
+
+    /// Like is_valid, but reports why the permission cannot be used
+    pub fn check_valid(&self, timestamp: i64) -> Result<()> {
+        require!(self.is_active, crate::DenftError::AccessRevoked);
+        require!(self.has_started(timestamp), crate::DenftError::AccessNotYetValid);
+        require!(!self.is_expired(timestamp), crate::DenftError::AccessExpired);
+        Ok(())
+    }
+
+    /// Granted before the file's current upload or reactivation, so it belonged to a predecessor
+    pub fn is_stale(&self, file_record: &FileRecord) -> bool {
+        self.granted_at < file_record.timestamp
+    }
+
+    pub fn has_started(&self, timestamp: i64) -> bool {
+        match self.not_before {
+            Some(start) => timestamp >= start,
+            None => true,
+        }
     }
 
     pub fn has_permission(&self, permission: u8) -> bool {
@@ -65,13 +180,57 @@ impl AccessPermission {
 
     pub fn can_download(&self) -> bool {
         self.has_permission(PERMISSION_DOWNLOAD) &&
-        self.max_downloads.map_or(true, |max| (self.used_downloads as u64) < max)
+        match self.max_downloads {
+            Some(max) => (self.used_downloads as u64) < max,
+            None => true,
+        }
+    }
+
+    /// Withdrawn by the owner, as opposed to a fresh account or one that ran out
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// The grant can no longer be used, so its holder may pay for a new one
+    pub fn is_spent(&self, timestamp: i64) -> bool {
+        !self.is_active ||
+        self.is_expired(timestamp) ||
+        (self.has_permission(PERMISSION_DOWNLOAD) && !self.can_download())
+    }
+
+    pub fn window_resets_at(&self) -> Option<i64> {
+        self.rate_limit.map(|limit| self.window_start + limit.window_seconds)
     }
 
-    pub fn consume_download(&mut self) -> Result<()> {
+    /// End of the current window while it has no downloads left, None when a download would pass
+    pub fn rate_limited_until(&self, timestamp: i64) -> Option<i64> {
+        let limit = self.rate_limit?;
+        let resets_at = self.window_resets_at()?;
+        (timestamp < resets_at && self.window_downloads >= limit.max_downloads).then_some(resets_at)
+    }
+
+    pub fn consume_download(&mut self, timestamp: i64) -> Result<()> {
         if let Some(max) = self.max_downloads {
             require!((self.used_downloads as u64) < max, crate::DenftError::DownloadLimitExceeded);
         }
+
+        if let Some(limit) = self.rate_limit {
+            // Start a fresh window once the previous one has elapsed
+            if timestamp >= self.window_start + limit.window_seconds {
+                self.window_start = timestamp;
+                self.window_downloads = 0;
+            }
+
+            // Return data is dropped with the failed transaction; clients read the reset time
+            // from check_download_window instead
+            if let Some(resets_at) = self.rate_limited_until(timestamp) {
+                msg!("Download rate limit reached, window resets at {}", resets_at);
+                return err!(crate::DenftError::DownloadRateLimited);
+            }
+
+            self.window_downloads += 1;
+        }
+
         self.used_downloads += 1;
         Ok(())
     }
@@ -81,7 +240,47 @@ impl AccessPermission {
         self.revoked_at = Some(timestamp);
     }
 
+    pub fn grant(
+        &mut self,
+        file_record: Pubkey,
+        accessor: Pubkey,
+        terms: &GrantTerms,
+        granted_by: Pubkey,
+        payer: Pubkey,
+        timestamp: i64,
+    ) {
+        self.file_record = file_record;
+        self.accessor = accessor;
+        self.permissions = terms.permissions;
+        self.granted_at = timestamp;
+        self.expires_at = terms.expires_at;
+        self.max_downloads = terms.max_downloads.map(|x| x as u64);
+        self.used_downloads = 0;
+        self.granted_by = granted_by;
+        self.is_active = true;
+        self.revoked_at = None;
+        self.not_before = terms.not_before;
+        self.rate_limit = terms.rate_limit;
+        self.window_downloads = 0;
+        self.window_start = timestamp;
+        self.payer = payer;
+        self.version = CURRENT_ACCOUNT_VERSION;
+        self.reserved = [0; 6];
+    }
+
     pub fn is_valid_permission(permissions: u8) -> bool {
         permissions > 0 && permissions <= 7 // 1 + 2 + 4 = 7 (read + download + share)
     }
+}
+
+impl Versioned for AccessPermission {
+    const FIXED_SPACE: usize = 8 + AccessPermission::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.migrate();
+    }
 }
\ No newline at end of file
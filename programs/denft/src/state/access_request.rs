@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct AccessRequest {
+    pub file_record: Pubkey,        // The file record access is requested for
+    pub requester: Pubkey,          // User asking for access, also the rent payer
+    pub permissions: u8,            // Requested permission bitflags (read=1, download=2, share=4)
+    pub message_hash: [u8; 32],     // Hash of the off-chain message sent to the owner
+    pub requested_at: i64,
+    pub expires_at: i64,            // Request can no longer be approved after this time
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl AccessRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        32 +  // requester
+        1 +   // permissions
+        32 +  // message_hash
+        8 +   // requested_at
+        8 +   // expires_at
+        16;   // reserved space
+
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp > self.expires_at
+    }
+}
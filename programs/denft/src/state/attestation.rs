@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_CLAIM_TYPE_LENGTH;
+
+#[account]
+pub struct Attestation {
+    pub file_record: Pubkey,        // File being attested
+    pub attester: Pubkey,           // Notary, auditor or issuer co-signing the hash, also the rent payer
+    pub file_hash: [u8; 32],        // Hash the attester signed off on
+    pub claim_type: String,         // What is being attested, e.g. "notarized" (4 + MAX_CLAIM_TYPE_LENGTH bytes)
+    pub attested_at: i64,
+    pub expires_at: Option<i64>,    // None = never expires
+    pub revoked: bool,
+    pub revoked_at: Option<i64>,
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl Attestation {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                          // file_record
+        32 +                          // attester
+        32 +                          // file_hash
+        (4 + MAX_CLAIM_TYPE_LENGTH) + // claim_type
+        8 +                           // attested_at
+        9 +                           // expires_at
+        1 +                           // revoked
+        9 +                           // revoked_at
+        16;                           // reserved space
+
+    pub fn is_current(&self, timestamp: i64) -> bool {
+        if self.revoked {
+            return false;
+        }
+        // Same boundary as AccessPermission and Credential: still current at the expiry timestamp itself
+        match self.expires_at {
+            Some(expires_at) => timestamp <= expires_at,
+            None => true,
+        }
+    }
+
+    pub fn revoke(&mut self, timestamp: i64) {
+        self.revoked = true;
+        self.revoked_at = Some(timestamp);
+    }
+
+    pub fn summary(&self) -> AttestationSummary {
+        AttestationSummary {
+            attester: self.attester,
+            claim_type: self.claim_type.clone(),
+            attested_at: self.attested_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+/// Current attestation as reported in verification output
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AttestationSummary {
+    pub attester: Pubkey,
+    pub claim_type: String,
+    pub attested_at: i64,
+    pub expires_at: Option<i64>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::{GrantTerms, DownloadRateLimit};
+
+#[account]
+pub struct ClaimableGrant {
+    pub file_record: Pubkey,        // The file record this pending grant applies to
+    pub claim_key_hash: [u8; 32],   // SHA-256 of the one-time claim public key
+    pub permissions: u8,            // Permission bitflags given to the claimer
+    pub expires_at: Option<i64>,    // Expiration of the resulting AccessPermission
+    pub max_downloads: Option<u32>, // Download cap of the resulting AccessPermission
+    pub claim_expires_at: i64,      // Link can no longer be claimed after this time
+    pub created_by: Pubkey,         // Owner who created the link, also the rent payer
+    pub created_at: i64,
+    pub not_before: Option<i64>,    // Start of the resulting AccessPermission (optional)
+    pub rate_limit: Option<DownloadRateLimit>, // Per-window download cap of the resulting AccessPermission
+    pub reserved: [u8; 7],          // Reserved space for future features
+}
+
+impl ClaimableGrant {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        32 +  // claim_key_hash
+        1 +   // permissions
+        9 +   // expires_at (Option<i64>)
+        5 +   // max_downloads (Option<u32>)
+        8 +   // claim_expires_at
+        32 +  // created_by
+        8 +   // created_at
+        9 +   // not_before (Option<i64>)
+        1 + DownloadRateLimit::LEN + // rate_limit (Option<DownloadRateLimit>)
+        7;    // reserved space
+
+    pub fn terms(&self) -> GrantTerms {
+        GrantTerms {
+            permissions: self.permissions,
+            not_before: self.not_before,
+            expires_at: self.expires_at,
+            max_downloads: self.max_downloads,
+            rate_limit: self.rate_limit,
+        }
+    }
+
+    pub fn hash_claim_key(claim_key: &Pubkey) -> [u8; 32] {
+        hash(claim_key.as_ref()).to_bytes()
+    }
+
+    pub fn matches_claim_key(&self, claim_key: &Pubkey) -> bool {
+        ClaimableGrant::hash_claim_key(claim_key) == self.claim_key_hash
+    }
+
+    pub fn is_claimable(&self, timestamp: i64) -> bool {
+        timestamp <= self.claim_expires_at
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_CO_OWNERS;
+
+#[account]
+pub struct CoOwnership {
+    pub file_record: Pubkey,       // The file record governed by this owner set
+    pub owners: Vec<Pubkey>,       // Co-owners allowed to propose and approve (max MAX_CO_OWNERS)
+    pub threshold: u8,             // Approvals required before a proposal can execute
+    pub proposal_count: u64,       // Number of proposals created, used as the next proposal id
+    pub created_at: i64,
+    pub reserved: [u8; 32],        // Reserved space for future features
+}
+
+impl CoOwnership {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                        // file_record
+        (4 + 32 * MAX_CO_OWNERS) +  // owners
+        1 +                         // threshold
+        8 +                         // proposal_count
+        8 +                         // created_at
+        32;                         // reserved space
+
+    pub fn owner_index(&self, key: &Pubkey) -> Option<usize> {
+        self.owners.iter().position(|owner| owner == key)
+    }
+
+    pub fn is_owner(&self, key: &Pubkey) -> bool {
+        self.owner_index(key).is_some()
+    }
+
+    pub fn is_valid_owner_set(owners: &[Pubkey], threshold: u8) -> bool {
+        let unique = owners
+            .iter()
+            .enumerate()
+            .all(|(i, owner)| !owners[..i].contains(owner));
+
+        unique &&
+        owners.len() >= 2 &&
+        owners.len() <= MAX_CO_OWNERS &&
+        threshold > 0 &&
+        (threshold as usize) <= owners.len()
+    }
+}
@@ -9,4 +9,15 @@ pub const MAX_SHARED_USERS_PER_FILE: u8 = 10;
 pub const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10MB
 pub const MAX_DESCRIPTION_LENGTH: usize = 500;
 pub const MAX_IPFS_HASH_LENGTH: usize = 100;
-pub const MAX_CONTENT_TYPE_LENGTH: usize = 100;
\ No newline at end of file
+pub const MAX_CONTENT_TYPE_LENGTH: usize = 100;
+pub const MAX_CO_OWNERS: usize = 10;
+pub const MAX_GROUP_NAME_LENGTH: usize = 32;
+pub const MAX_ACCESS_LOG_CAPACITY: u16 = 200;
+pub const TRASH_RETENTION_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const DISPUTE_RESOLUTION_PERIOD: i64 = 30 * 24 * 60 * 60; // Arbiter deadline before the client can refund
+pub const MAX_CLAIM_TYPE_LENGTH: usize = 32;
+pub const MAX_SCHEMA_ID_LENGTH: usize = 64;
+pub const MAX_ENCRYPTED_METADATA_LENGTH: usize = 256;
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1; // Version 0 is the unversioned layout
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000; // 10% of the sale price
+pub const CREDENTIAL_STATUS_LIST_BYTES: usize = 1_024; // 8192 credentials per status list
\ No newline at end of file
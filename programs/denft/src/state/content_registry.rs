@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ContentRegistry {
+    pub file_hash: [u8; 32],            // Content hash, the only PDA seed
+    pub first_uploader: Pubkey,         // Wallet that registered the hash first, also the rent payer
+    pub first_file_record: Pubkey,      // FileRecord created by the first upload
+    pub first_timestamp: i64,
+    pub first_slot: u64,
+    pub subsequent_registrations: u64,  // Uploads of the same hash after the first
+    pub reserved: [u8; 16],             // Reserved space for future features
+}
+
+impl ContentRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_hash
+        32 +  // first_uploader
+        32 +  // first_file_record
+        8 +   // first_timestamp
+        8 +   // first_slot
+        8 +   // subsequent_registrations
+        16;   // reserved space
+
+    pub fn is_registered(&self) -> bool {
+        self.first_uploader != Pubkey::default()
+    }
+
+    /// Record an upload of this hash; only the first one claims priority
+    pub fn register(&mut self, file_hash: [u8; 32], uploader: Pubkey, file_record: Pubkey, timestamp: i64, slot: u64) {
+        if self.is_registered() {
+            self.subsequent_registrations += 1;
+            return;
+        }
+
+        self.file_hash = file_hash;
+        self.first_uploader = uploader;
+        self.first_file_record = file_record;
+        self.first_timestamp = timestamp;
+        self.first_slot = slot;
+        self.subsequent_registrations = 0;
+        self.reserved = [0; 16];
+    }
+}
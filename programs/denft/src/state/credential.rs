@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{CredentialStatusList, MAX_SCHEMA_ID_LENGTH};
+
+#[account]
+pub struct Credential {
+    pub issuer: Pubkey,             // Issuing organisation, also the rent payer
+    pub subject: Pubkey,            // Wallet the credential is about
+    pub credential_hash: [u8; 32],  // Hash of the off-chain credential document
+    pub schema_id: String,          // Credential schema identifier (4 + MAX_SCHEMA_ID_LENGTH bytes)
+    pub issued_at: i64,
+    pub expires_at: Option<i64>,    // None = never expires
+    pub revoked: bool,              // Revocation status, kept on-chain once set
+    pub revoked_at: Option<i64>,
+    pub status_list: Pubkey,        // CredentialStatusList holding this credential's revocation bit
+    pub status_index: u32,          // Bit index within the status list
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl Credential {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                          // issuer
+        32 +                          // subject
+        32 +                          // credential_hash
+        (4 + MAX_SCHEMA_ID_LENGTH) +  // schema_id
+        8 +                           // issued_at
+        9 +                           // expires_at
+        1 +                           // revoked
+        9 +                           // revoked_at
+        32 +                          // status_list
+        4 +                           // status_index
+        16;                           // reserved space
+
+    /// Revocation, read from the status list, takes precedence over expiry
+    pub fn status(&self, status_list: &CredentialStatusList, timestamp: i64) -> CredentialStatus {
+        if status_list.is_revoked(self.status_index) {
+            return CredentialStatus::Revoked;
+        }
+        // Same boundary as AccessPermission: still valid at the expiry timestamp itself
+        match self.expires_at {
+            Some(expires_at) if timestamp > expires_at => CredentialStatus::Expired,
+            _ => CredentialStatus::Valid,
+        }
+    }
+
+    pub fn revoke(&mut self, timestamp: i64) {
+        self.revoked = true;
+        self.revoked_at = Some(timestamp);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CredentialStatus {
+    Valid,
+    Expired,
+    Revoked,
+}
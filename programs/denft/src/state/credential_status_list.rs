@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::CREDENTIAL_STATUS_LIST_BYTES;
+
+/// Revocation bitmap for a batch of one issuer's credentials, so verifiers can check
+/// many credentials by fetching a single account
+#[account]
+pub struct CredentialStatusList {
+    pub issuer: Pubkey,             // Issuer whose credentials are indexed here, also the rent payer
+    pub list_id: u32,               // Issuer-chosen list number, part of the PDA seeds
+    pub issued: u32,                // Indices handed out so far, also the next free index
+    pub revoked: [u8; CREDENTIAL_STATUS_LIST_BYTES], // Bit i is set once credential i is revoked
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl CredentialStatusList {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                            // issuer
+        4 +                             // list_id
+        4 +                             // issued
+        CREDENTIAL_STATUS_LIST_BYTES +  // revoked
+        16;                             // reserved space
+
+    pub const CAPACITY: u32 = (CREDENTIAL_STATUS_LIST_BYTES * 8) as u32;
+
+    pub fn is_full(&self) -> bool {
+        self.issued >= Self::CAPACITY
+    }
+
+    /// Hand out the next index; callers check `is_full` first
+    pub fn allocate(&mut self) -> u32 {
+        let index = self.issued;
+        self.issued += 1;
+        index
+    }
+
+    pub fn is_revoked(&self, index: u32) -> bool {
+        self.revoked[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn revoke(&mut self, index: u32) {
+        self.revoked[index as usize / 8] |= 1 << (index % 8);
+    }
+}
\ No newline at end of file
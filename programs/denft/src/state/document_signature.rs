@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct DocumentSignature {
+    pub file_record: Pubkey,        // File whose hash was signed
+    pub signer: Pubkey,             // Wallet that produced the off-chain ed25519 signature
+    pub file_hash: [u8; 32],        // Message that was signed
+    pub signature: [u8; 64],        // Raw ed25519 signature, checked by the native program
+    pub recorded_by: Pubkey,        // Who submitted the signature, also the rent payer
+    pub recorded_at: i64,           // Notarisation time
+    pub slot: u64,
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl DocumentSignature {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        32 +  // signer
+        32 +  // file_hash
+        64 +  // signature
+        32 +  // recorded_by
+        8 +   // recorded_at
+        8 +   // slot
+        16;   // reserved space
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::DISPUTE_RESOLUTION_PERIOD;
+
+#[account]
+pub struct Escrow {
+    pub client: Pubkey,             // Pays into the escrow, gets refunds and the rent back
+    pub freelancer: Pubkey,         // Delivers the file and receives the funds on release
+    pub arbiter: Pubkey,            // Settles the escrow once the client disputes a delivery
+    pub file_hash: [u8; 32],        // Hash of the deliverable, matched against the freelancer's FileRecord
+    pub amount: u64,                // Escrowed lamports, held on top of the account rent
+    pub created_at: i64,
+    pub deadline: i64,              // Undelivered escrows can be refunded after this time
+    pub dispute_window: i64,        // Seconds after delivery during which the client may reject it
+    pub delivered_at: Option<i64>,  // Set once the file is uploaded and shared with the client
+    pub disputed_at: Option<i64>,   // Set when the client disputes; the funds then wait for the arbiter
+    pub file_record: Pubkey,        // Delivered FileRecord, default until delivery
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl Escrow {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // client
+        32 +  // freelancer
+        32 +  // arbiter
+        32 +  // file_hash
+        8 +   // amount
+        8 +   // created_at
+        8 +   // deadline
+        8 +   // dispute_window
+        9 +   // delivered_at (Option<i64>)
+        9 +   // disputed_at (Option<i64>)
+        32 +  // file_record
+        16;   // reserved space
+
+    pub fn is_delivered(&self) -> bool {
+        self.delivered_at.is_some()
+    }
+
+    pub fn is_disputed(&self) -> bool {
+        self.disputed_at.is_some()
+    }
+
+    /// The client can still reject the delivery
+    pub fn in_dispute_window(&self, timestamp: i64) -> bool {
+        match self.delivered_at {
+            Some(delivered_at) => timestamp <= delivered_at + self.dispute_window,
+            None => false,
+        }
+    }
+
+    /// A delivery is disputed once, inside its window; the client keeps their access meanwhile
+    pub fn is_disputable(&self, timestamp: i64) -> bool {
+        self.in_dispute_window(timestamp) && !self.is_disputed()
+    }
+
+    /// The client alone can only take the funds back when nothing was delivered in time,
+    /// or when the arbiter left a dispute unresolved past the resolution period
+    pub fn is_refundable(&self, timestamp: i64) -> bool {
+        match self.disputed_at {
+            Some(disputed_at) => timestamp > disputed_at + DISPUTE_RESOLUTION_PERIOD,
+            None => !self.is_delivered() && timestamp > self.deadline,
+        }
+    }
+
+    /// Freelancers can claim once the client let the dispute window pass without disputing
+    pub fn is_claimable(&self, timestamp: i64) -> bool {
+        self.is_delivered() && !self.in_dispute_window(timestamp) && !self.is_disputed()
+    }
+
+    /// Move the escrowed amount out; the rent stays for whoever closes the account
+    pub fn pay_out(escrow: &AccountInfo, recipient: &AccountInfo, amount: u64) -> Result<()> {
+        **escrow.try_borrow_mut_lamports()? -= amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ExistenceProof {
+    pub owner: Pubkey,              // Who anchored the hash, also the rent payer
+    pub file_hash: [u8; 32],        // SHA-256 of the document; the document itself is never stored
+    pub slot: u64,                  // Slot the proof was recorded in
+    pub timestamp: i64,             // Unix time the proof was recorded at
+}
+
+impl ExistenceProof {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // owner
+        32 +  // file_hash
+        8 +   // slot
+        8;    // timestamp
+
+    pub fn verify_hash(&self, hash: &[u8; 32]) -> bool {
+        self.file_hash == *hash
+    }
+}
@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
-use crate::{MAX_IPFS_HASH_LENGTH, MAX_CONTENT_TYPE_LENGTH, MAX_DESCRIPTION_LENGTH};
+use crate::{
+  DenftError, MAX_IPFS_HASH_LENGTH, MAX_ENCRYPTED_METADATA_LENGTH, MAX_CONTENT_TYPE_LENGTH,
+  MAX_DESCRIPTION_LENGTH, TRASH_RETENTION_PERIOD, CURRENT_ACCOUNT_VERSION, Versioned,
+};
 
 #[account]
 pub struct FileRecord {
-  pub owner: Pubkey,               // Owner of the file
+  pub owner: Pubkey,               // Owner of the file, ignored while tokenized
   pub file_hash: [u8; 32],         // SHA-256 hash of the file content
   pub ipfs_hash: String,           // IPFS content identifier  (4 + MAX_IPFS_LENGTH bytes)
   pub encrypted_metadata: String,  // Encrypted metadata (4 + dynamic size)
@@ -16,32 +20,96 @@ pub struct FileRecord {
   pub access_count: u64,             // Number of times accessed
   pub download_count: u64,          // Number of times downloaded
   pub is_active: bool,              // File active status
-  pub deleted_at: Option<i64>,
+  pub deleted_at: Option<i64>,     // Last deletion, kept as history once the file is reactivated
   pub verification_id: u64,          // Unique verification ID
-  pub reserved: [u8; 32],           // Reserved for future use
+  pub co_owned: bool,               // Privileged operations go through CoOwnership proposals
+  pub not_before: Option<i64>,      // Time-locked release: no access or public verification before this
+  pub has_access_log: bool,         // Accesses must be written to the file's AccessLog
+  pub retention_until: Option<i64>, // WORM lock: no deletion, downgrade or transfer before this
+  pub legal_hold: bool,             // WORM lock held until explicitly released
+  pub uploader: Pubkey,             // Original uploader: PDA seed, quota account and rent recipient
+  pub ownership_mint: Option<Pubkey>, // Supply-1 mint whose holder acts as owner while tokenized
+  pub has_token_gate: bool,         // A TokenGate admits holders and restricts private verification
+  pub version: u8,                  // Layout version, 0 for records created before versioning
+  pub event_sequence: u64,          // Last sequence number stamped on one of this file's events
+  pub reserved: [u8; 1],            // Reserved for future use
   
 } 
 
 
 impl FileRecord {
-  pub const BASE_LEN: usize =  8 +
+  /// Everything except the string contents (their 4-byte length prefixes are included)
+  pub const FIXED_LEN: usize = 8 +
     32 +
     32 +
-    (4 + MAX_IPFS_HASH_LENGTH) +
-    (4 + 256) + // choose a sensible cap for encrypted metadata
+    4 + // ipfs_hash
+    4 + // encrypted_metadata
     8 +
-    (4 + MAX_CONTENT_TYPE_LENGTH) +
-    (4 + MAX_DESCRIPTION_LENGTH) +
-    8 + 1 + 8 + 8 + 1 + 9 + 8 + 32;
+    4 + // content_type
+    4 + // description
+    8 + 1 + 8 + 8 + 1 + 9 + 8 + 1 + 9 + 1 + 9 + 1 + 32 + 33 + 1 + 1 + 8 + 1;
 
-  pub fn space_required() -> usize {
-    FileRecord::BASE_LEN
+  /// Largest possible record, with every string at its limit
+  pub const BASE_LEN: usize = FileRecord::FIXED_LEN +
+    MAX_IPFS_HASH_LENGTH +
+    MAX_ENCRYPTED_METADATA_LENGTH +
+    MAX_CONTENT_TYPE_LENGTH +
+    MAX_DESCRIPTION_LENGTH;
+
+  /// Exact space for the given string lengths; records are sized on upload and resized on edit
+  pub fn space_for(
+    ipfs_hash_len: usize,
+    encrypted_metadata_len: usize,
+    content_type_len: usize,
+    description_len: usize,
+  ) -> usize {
+    FileRecord::FIXED_LEN + ipfs_hash_len + encrypted_metadata_len + content_type_len + description_len
+  }
+
+  /// Upgrade the layout one version at a time up to the current one
+  pub fn migrate(&mut self) {
+    // v0 -> v1: the version byte was carved out of `reserved`. Records older than the
+    // `uploader` field read it as the default key; such records could never be tokenized,
+    // so `owner` is still the uploader their PDA was derived from
+    if self.version == 0 {
+      if self.uploader == Pubkey::default() {
+        self.uploader = self.owner;
+      }
+      self.version = 1;
+    }
+    debug_assert_eq!(self.version, CURRENT_ACCOUNT_VERSION);
+  }
+
+  pub fn is_tokenized(&self) -> bool {
+    self.ownership_mint.is_some()
+  }
+
+  /// While tokenized, ownership follows the token rather than the `owner` field
+  pub fn is_owned_by(&self, authority: &Pubkey, owner_token_account: Option<&TokenAccount>) -> bool {
+    match self.ownership_mint {
+      Some(mint) => match owner_token_account {
+        Some(token_account) => {
+          token_account.mint == mint &&
+          token_account.owner == *authority &&
+          token_account.amount == 1
+        }
+        None => false,
+      },
+      None => self.owner == *authority,
+    }
   }
 
   pub fn is_accessible(&self) -> bool {
     self.is_active
   }
 
+  pub fn is_released(&self, timestamp: i64) -> bool {
+    match self.not_before {
+      Some(start) => timestamp >= start,
+      None => true,
+    }
+  }
+
   pub fn increment_access(&mut self) {
     self.access_count += 1;
   }
@@ -50,6 +118,37 @@ impl FileRecord {
     self.download_count += 1;
   }
 
+  /// Sequence numbers start at 1 and never skip, so indexers can detect missed events
+  pub fn next_event_sequence(&mut self) -> u64 {
+    self.event_sequence += 1;
+    self.event_sequence
+  }
+
+  /// Same as `next_event_sequence` for a record that may already have been purged, which reports 0
+  /// since the purge has already stamped the tombstone with the record's final sequence
+  pub fn next_event_sequence_of(info: &AccountInfo) -> Result<u64> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+      return Ok(0);
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    let mut file_record = FileRecord::try_deserialize(&mut &data[..])?;
+    let sequence = file_record.next_event_sequence();
+    file_record.try_serialize(&mut &mut data[..])?;
+
+    Ok(sequence)
+  }
+
+  /// Decode a record that may already have been purged, returning None once it is closed
+  pub fn load_open(info: &AccountInfo) -> Result<Option<FileRecord>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+      return Ok(None);
+    }
+
+    let data = info.try_borrow_data()?;
+    Ok(Some(FileRecord::try_deserialize(&mut &data[..])?))
+  }
+
   pub fn verify_hash(&self, provided_hash: &[u8; 32]) -> bool  {
     self.file_hash == *provided_hash
   }
@@ -58,4 +157,75 @@ impl FileRecord {
     self.is_active = false;
     self.deleted_at = Some(timestamp);
   }
+
+  pub fn restore(&mut self) {
+    self.is_active = true;
+    self.deleted_at = None;
+  }
+
+  /// Bring a deleted record back as a fresh upload of the same content
+  pub fn reactivate(
+    &mut self,
+    ipfs_hash: String,
+    encrypted_metadata: String,
+    content_type: String,
+    description: String,
+    timestamp: i64,
+    verification_id: u64,
+  ) {
+    self.ipfs_hash = ipfs_hash;
+    self.encrypted_metadata = encrypted_metadata;
+    self.content_type = content_type;
+    self.description = description;
+    self.timestamp = timestamp;
+    self.access_count = 0;
+    self.download_count = 0;
+    self.verification_id = verification_id;
+    self.is_active = true;
+  }
+
+  /// A deleted file stays in the trash, restorable, until the retention period ends
+  pub fn is_in_trash(&self, timestamp: i64) -> bool {
+    match self.deleted_at {
+      Some(deleted_at) => !self.is_active && timestamp <= deleted_at + TRASH_RETENTION_PERIOD,
+      None => false,
+    }
+  }
+
+  pub fn is_purgeable(&self, timestamp: i64) -> bool {
+    match self.deleted_at {
+      Some(deleted_at) => !self.is_active && timestamp > deleted_at + TRASH_RETENTION_PERIOD,
+      None => false,
+    }
+  }
+
+  /// Fails while a legal hold or retention lock protects the record
+  pub fn check_not_locked(&self, timestamp: i64) -> Result<()> {
+    require!(!self.legal_hold, DenftError::FileUnderLegalHold);
+    if let Some(retention_until) = self.retention_until {
+      require!(timestamp >= retention_until, DenftError::FileUnderRetention);
+    }
+    Ok(())
+  }
+}
+
+impl Versioned for FileRecord {
+  const FIXED_SPACE: usize = FileRecord::FIXED_LEN;
+
+  fn version(&self) -> u8 {
+    self.version
+  }
+
+  fn upgrade(&mut self) {
+    self.migrate();
+  }
+
+  fn required_space(&self) -> usize {
+    FileRecord::space_for(
+      self.ipfs_hash.len(),
+      self.encrypted_metadata.len(),
+      self.content_type.len(),
+      self.description.len(),
+    )
+  }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Left behind when a FileRecord is purged, so a later upload to the same address
+/// continues its event sequence instead of restarting at zero
+#[account]
+pub struct FileTombstone {
+    pub file_record: Pubkey,        // Address of the purged record, the only PDA seed
+    pub last_sequence: u64,         // Sequence of the record's final event, FilePurged
+    pub purged_at: i64,
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl FileTombstone {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // file_record
+        8 +   // last_sequence
+        8 +   // purged_at
+        16;   // reserved space
+
+    /// Tombstone of an earlier purge at this address, if there was one
+    pub fn load(info: &AccountInfo) -> Result<Option<FileTombstone>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = info.try_borrow_data()?;
+        Ok(Some(FileTombstone::try_deserialize(&mut &data[..])?))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_GROUP_NAME_LENGTH;
+
+#[account]
+pub struct Group {
+    pub admin: Pubkey,          // Manages membership of the group
+    pub name: String,           // Group name, also part of the PDA seeds (4 + MAX_GROUP_NAME_LENGTH bytes)
+    pub member_count: u32,      // Number of active memberships
+    pub created_at: i64,
+    pub reserved: [u8; 32],     // Reserved space for future features
+}
+
+impl Group {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                          // admin
+        (4 + MAX_GROUP_NAME_LENGTH) + // name
+        4 +                           // member_count
+        8 +                           // created_at
+        32;                           // reserved space
+
+    pub fn add_member(&mut self) {
+        self.member_count += 1;
+    }
+
+    pub fn remove_member(&mut self) {
+        self.member_count = self.member_count.saturating_sub(1);
+    }
+}
+
+#[account]
+pub struct GroupMembership {
+    pub group: Pubkey,          // Group this membership belongs to
+    pub member: Pubkey,         // Member wallet
+    pub added_by: Pubkey,       // Admin who added the member
+    pub added_at: i64,
+    pub reserved: [u8; 16],     // Reserved space for future features
+}
+
+impl GroupMembership {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // group
+        32 +  // member
+        32 +  // added_by
+        8 +   // added_at
+        16;   // reserved space
+}
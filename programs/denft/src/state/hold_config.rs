@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct HoldConfig {
+    pub hold_authority: Pubkey,     // Places and releases legal holds on files
+    pub updated_by: Pubkey,         // Upgrade authority that last appointed the hold authority
+    pub updated_at: i64,
+    pub reserved: [u8; 32],         // Reserved space for future features
+}
+
+impl HoldConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // hold_authority
+        32 +  // updated_by
+        8 +   // updated_at
+        32;   // reserved space
+}
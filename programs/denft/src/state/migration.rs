@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{DenftError, CURRENT_ACCOUNT_VERSION};
+
+/// Accounts with a layout version that `migrate_account` can upgrade in place
+pub trait Versioned: AccountSerialize + AccountDeserialize {
+    /// Size of the current layout, or of its fixed part for accounts holding strings
+    const FIXED_SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    fn upgrade(&mut self);
+
+    fn required_space(&self) -> usize {
+        Self::FIXED_SPACE
+    }
+}
+
+/// Upgrade an account written by any earlier layout, returning the version it started from.
+///
+/// Older layouts can be shorter than the current one, so they cannot be loaded as
+/// `Account<T>`. Fields added since then read as zero, so the data is zero-extended before
+/// decoding, and the account is grown and topped up to rent exemption before writing back.
+pub fn migrate_account<'info, T: Versioned>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    let mut account = {
+        let mut data = info.try_borrow_data()?.to_vec();
+        data.resize(data.len() + T::FIXED_SPACE, 0);
+        T::try_deserialize(&mut &data[..])?
+    };
+
+    let from_version = account.version();
+    require!(from_version < CURRENT_ACCOUNT_VERSION, DenftError::AccountAlreadyMigrated);
+    account.upgrade();
+
+    // Grown if needed, never shrunk
+    let space = account.required_space().max(info.data_len());
+    if space > info.data_len() {
+        let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        info.realloc(space, true)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])?;
+
+    Ok(from_version)
+}
\ No newline at end of file
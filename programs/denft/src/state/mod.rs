@@ -1,9 +1,47 @@
 pub mod user_account;
 pub mod file_record;
 pub mod access_permission;
+pub mod co_ownership;
+pub mod proposal;
+pub mod access_request;
+pub mod group;
+pub mod claimable_grant;
+pub mod access_log;
+pub mod hold_config;
+pub mod existence_proof;
+pub mod attestation;
+pub mod document_signature;
+pub mod credential;
+pub mod credential_status_list;
+pub mod token_gate;
+pub mod content_registry;
+pub mod access_listing;
+pub mod protocol_config;
+pub mod escrow;
+pub mod file_tombstone;
+pub mod migration;
 pub mod constants;
 
 pub use user_account::*;
 pub use file_record::*;
 pub use access_permission::*;
-pub use constants::*;
\ No newline at end of file
+pub use co_ownership::*;
+pub use proposal::*;
+pub use access_request::*;
+pub use group::*;
+pub use claimable_grant::*;
+pub use access_log::*;
+pub use hold_config::*;
+pub use existence_proof::*;
+pub use attestation::*;
+pub use document_signature::*;
+pub use credential::*;
+pub use credential_status_list::*;
+pub use token_gate::*;
+pub use content_registry::*;
+pub use access_listing::*;
+pub use protocol_config::*;
+pub use escrow::*;
+pub use file_tombstone::*;
+pub use migration::*;
+pub use constants::*;
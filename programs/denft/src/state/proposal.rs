@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{CoOwnership, DenftError, DownloadRateLimit};
+
+/// Privileged file operations that a co-owned file executes through proposals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    DeleteFile,
+    GrantAccess {
+        accessor: Pubkey,
+        permissions: u8,
+        expires_at: Option<i64>,
+        max_downloads: Option<u32>,
+        not_before: Option<i64>,
+        rate_limit: Option<DownloadRateLimit>,
+    },
+    RevokeAccess {
+        accessor: Pubkey,
+    },
+    UpdateFilePublicity {
+        is_public: bool,
+    },
+}
+
+impl ProposalAction {
+    // Largest variant is GrantAccess: tag + accessor + permissions + expires_at + max_downloads + not_before + rate_limit
+    pub const LEN: usize = 1 + 32 + 1 + 9 + 5 + 9 + (1 + DownloadRateLimit::LEN);
+}
+
+#[account]
+pub struct Proposal {
+    pub file_record: Pubkey,       // The co-owned file this proposal acts on
+    pub proposal_id: u64,          // Sequential id within the file's CoOwnership
+    pub proposer: Pubkey,          // Co-owner who created the proposal
+    pub action: ProposalAction,    // Operation to perform once approved
+    pub approvals: u16,            // Bitmask over CoOwnership.owners indices
+    pub created_at: i64,
+    pub is_executed: bool,
+    pub executed_at: Option<i64>,
+    pub reserved: [u8; 16],        // Reserved space for future features
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                  // file_record
+        8 +                   // proposal_id
+        32 +                  // proposer
+        ProposalAction::LEN + // action
+        2 +                   // approvals
+        8 +                   // created_at
+        1 +                   // is_executed
+        9 +                   // executed_at (Option<i64>)
+        16;                   // reserved space
+
+    pub fn approve(&mut self, owner_index: usize) {
+        self.approvals |= 1 << owner_index;
+    }
+
+    pub fn has_approved(&self, owner_index: usize) -> bool {
+        (self.approvals & (1 << owner_index)) != 0
+    }
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+
+    pub fn is_approved(&self, threshold: u8) -> bool {
+        self.approval_count() >= threshold
+    }
+
+    pub fn check_executable(&self, co_ownership: &CoOwnership, executor: &Pubkey) -> Result<()> {
+        require!(!self.is_executed, DenftError::ProposalAlreadyExecuted);
+        require!(co_ownership.is_owner(executor), DenftError::NotCoOwner);
+        require!(self.is_approved(co_ownership.threshold), DenftError::ProposalThresholdNotMet);
+        Ok(())
+    }
+
+    pub fn mark_executed(&mut self, timestamp: i64) {
+        self.is_executed = true;
+        self.executed_at = Some(timestamp);
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ProtocolConfig {
+    pub treasury: Pubkey,           // Receives the protocol's cut of every sale
+    pub fee_bps: u16,               // Protocol fee in basis points of the sale price
+    pub updated_by: Pubkey,         // Upgrade authority that last changed the settings
+    pub updated_at: i64,
+    pub reserved: [u8; 32],         // Reserved space for future features
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 +  // treasury
+        2 +   // fee_bps
+        32 +  // updated_by
+        8 +   // updated_at
+        32;   // reserved space
+
+    /// Fee settings if they have been initialized; no fee is charged before that
+    pub fn load(info: &AccountInfo) -> Result<Option<ProtocolConfig>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = info.try_borrow_data()?;
+        Ok(Some(ProtocolConfig::try_deserialize(&mut &data[..])?))
+    }
+
+    pub fn fee_for(&self, price: u64) -> u64 {
+        (price as u128 * self.fee_bps as u128 / 10_000) as u64
+    }
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+use anchor_spl::token::TokenAccount;
+
+use crate::DenftError;
+
+/// Metaplex Token Metadata program, owner of NFT metadata accounts
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// What a caller must hold to pass a token gate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenGateKind {
+    Mint { mint: Pubkey, min_balance: u64 },
+    Collection { collection: Pubkey },
+}
+
+impl TokenGateKind {
+    pub const LEN: usize = 1 + 32 + 8; // tag + largest variant
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            TokenGateKind::Mint { min_balance, .. } => *min_balance > 0,
+            TokenGateKind::Collection { .. } => true,
+        }
+    }
+}
+
+#[account]
+pub struct TokenGate {
+    pub file_record: Pubkey,        // File the gate is attached to
+    pub kind: TokenGateKind,
+    pub permissions: u8,            // Bitflags granted to holders (read=1, download=2, share=4)
+    pub created_by: Pubkey,         // Owner who attached the gate, also the rent payer
+    pub created_at: i64,
+    pub reserved: [u8; 16],         // Reserved space for future features
+}
+
+impl TokenGate {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                    // file_record
+        TokenGateKind::LEN +    // kind
+        1 +                     // permissions
+        32 +                    // created_by
+        8 +                     // created_at
+        16;                     // reserved space
+
+    pub fn has_permission(&self, permission: u8) -> bool {
+        (self.permissions & permission) != 0
+    }
+
+    /// Check that `holder` satisfies the gate with the given token account and,
+    /// for collection gates, the NFT's metadata account
+    pub fn check_holder(
+        &self,
+        holder: &Pubkey,
+        token_account: Option<&TokenAccount>,
+        metadata: Option<&AccountInfo>,
+    ) -> Result<()> {
+        let token_account = token_account.ok_or(DenftError::TokenGateNotSatisfied)?;
+        require!(token_account.owner == *holder, DenftError::TokenGateNotSatisfied);
+
+        match self.kind {
+            TokenGateKind::Mint { mint, min_balance } => {
+                require!(
+                    token_account.mint == mint && token_account.amount >= min_balance,
+                    DenftError::TokenGateNotSatisfied
+                );
+            }
+            TokenGateKind::Collection { collection } => {
+                require!(token_account.amount >= 1, DenftError::TokenGateNotSatisfied);
+
+                let metadata = metadata.ok_or(DenftError::TokenGateNotSatisfied)?;
+                let (expected, _) = Pubkey::find_program_address(
+                    &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), token_account.mint.as_ref()],
+                    &TOKEN_METADATA_PROGRAM_ID,
+                );
+                require!(
+                    metadata.key() == expected && metadata.owner == &TOKEN_METADATA_PROGRAM_ID,
+                    DenftError::TokenGateNotSatisfied
+                );
+
+                // Only verified collection membership counts
+                let data = metadata.try_borrow_data()?;
+                require!(
+                    metadata_collection(&data) == Some((true, collection)),
+                    DenftError::TokenGateNotSatisfied
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Read the `collection` field of a Metaplex metadata account as (verified, key)
+fn metadata_collection(data: &[u8]) -> Option<(bool, Pubkey)> {
+    let mut cursor = MetadataCursor { data, offset: 0 };
+
+    cursor.skip(1 + 32 + 32)?;          // key, update_authority, mint
+    for _ in 0..3 {
+        let len = cursor.read_u32()? as usize;
+        cursor.skip(len)?;              // name, symbol, uri
+    }
+    cursor.skip(2)?;                    // seller_fee_basis_points
+    if cursor.read_u8()? == 1 {
+        let creators = cursor.read_u32()? as usize;
+        cursor.skip(creators.checked_mul(32 + 1 + 1)?)?;
+    }
+    cursor.skip(1 + 1)?;                // primary_sale_happened, is_mutable
+    for _ in 0..2 {
+        if cursor.read_u8()? == 1 {
+            cursor.skip(1)?;            // edition_nonce, token_standard
+        }
+    }
+    if cursor.read_u8()? != 1 {
+        return None;
+    }
+    let verified = cursor.read_u8()? == 1;
+    let key = Pubkey::try_from(cursor.take(32)?).ok()?;
+    Some((verified, key))
+}
+
+struct MetadataCursor<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> MetadataCursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{Versioned, CURRENT_ACCOUNT_VERSION};
+
 
 #[account]
 pub struct UserAccount {
@@ -10,7 +12,8 @@ pub struct UserAccount {
     pub file_limit: u32,     // Maximut number of files allowed (4 bytes)
     pub created_at: i64,     // Account creation timestamp
     pub is_active: bool,     // Account status
-    pub reserved: [u8; 64],  // Reserved space for future features (64 bytes)
+    pub version: u8,         // Layout version, 0 for accounts created before versioning
+    pub reserved: [u8; 63],  // Reserved space for future features (63 bytes)
 }
 
 impl UserAccount {
@@ -22,7 +25,8 @@ impl UserAccount {
         4 +     // file_limit (u32)
         8 +     // created_at (i64)
         1 +     // is_active (bool)
-        64;    // reserved space (64 bytes)
+        1 +     // version (u8)
+        63;    // reserved space (63 bytes)
 
 
         pub fn can_add_file(&self, file_size: u64) -> bool {
@@ -40,5 +44,25 @@ impl UserAccount {
             self.file_count = self.file_count.saturating_sub(1);
             self.storage_used = self.storage_used.saturating_sub(file_size);
         }
+
+        /// Upgrade the layout one version at a time up to the current one
+        pub fn migrate(&mut self) {
+            // v0 -> v1: the version byte was carved out of `reserved`, no field changes
+            if self.version == 0 {
+                self.version = 1;
+            }
+            debug_assert_eq!(self.version, CURRENT_ACCOUNT_VERSION);
+        }
 }
 
+impl Versioned for UserAccount {
+    const FIXED_SPACE: usize = 8 + UserAccount::LEN;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.migrate();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+
+use crate::errors::DenftError;
+
+// Layout of the ed25519 native program instruction data
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Check that an ed25519 program instruction verified `signer`'s signature over `message`
+/// and return the signature bytes. Only a single signature with all data inline is accepted.
+pub fn verify_ed25519_instruction(
+    instruction: &Instruction,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 64]> {
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        DenftError::InvalidSignatureInstruction
+    );
+
+    let data = &instruction.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN && data[0] == 1,
+        DenftError::InvalidSignatureInstruction
+    );
+
+    let read_u16 = |index: usize| -> u16 {
+        let at = SIGNATURE_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_offset = read_u16(0) as usize;
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_data_offset = read_u16(4) as usize;
+    let message_data_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    // Data must live in the ed25519 instruction itself, not be pulled from another instruction
+    require!(
+        signature_instruction_index == CURRENT_INSTRUCTION &&
+        public_key_instruction_index == CURRENT_INSTRUCTION &&
+        message_instruction_index == CURRENT_INSTRUCTION,
+        DenftError::InvalidSignatureInstruction
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + PUBKEY_LEN)
+        .ok_or(DenftError::InvalidSignatureInstruction)?;
+    let signature = data
+        .get(signature_offset..signature_offset + SIGNATURE_LEN)
+        .ok_or(DenftError::InvalidSignatureInstruction)?;
+    let signed_message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(DenftError::InvalidSignatureInstruction)?;
+
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        DenftError::SignatureMismatch
+    );
+
+    let mut signature_bytes = [0u8; 64];
+    signature_bytes.copy_from_slice(signature);
+    Ok(signature_bytes)
+}